@@ -0,0 +1,284 @@
+//! Terminal playback of the solvers' progress, one frame per step of a simulation.
+//!
+//! Solvers hand their frames over with [`frame`], which does nothing until a [`Player`] is
+//! [`start`]ed, so that animations cost nothing otherwise. Frames are played on stderr at a
+//! fixed rate, recorded to an asciinema cast file, or both.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use geometry::Pos;
+use termcolor::{Ansi, ColorSpec, WriteColor};
+
+pub use termcolor::Color;
+
+/// Moves the cursor home and clears the terminal.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// A grid of colored chars, with a caption under it.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<(char, Option<Color>)>,
+    caption: String,
+}
+
+impl Frame {
+    /// A `width` by `height` frame of blanks.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![(' ', None); width * height],
+            caption: String::new(),
+        }
+    }
+
+    /// Draws `glyph` at `pos`, positions out of the frame are ignored.
+    pub fn set(&mut self, pos: Pos, glyph: char, color: Option<Color>) {
+        let (Ok(x), Ok(y)) = (usize::try_from(pos.x), usize::try_from(pos.y)) else {
+            return;
+        };
+        if x < self.height && y < self.width {
+            self.cells[x * self.width + y] = (glyph, color);
+        }
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    /// The frame as text with ANSI colors, lines ending with `newline`.
+    fn to_ansi(&self, newline: &str) -> io::Result<String> {
+        let mut out = Ansi::new(Vec::new());
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut current = None;
+            for &(glyph, color) in row {
+                if color != current {
+                    match color {
+                        Some(color) => out.set_color(ColorSpec::new().set_fg(Some(color)))?,
+                        None => out.reset()?,
+                    }
+                    current = color;
+                }
+                write!(out, "{glyph}")?;
+            }
+            if current.is_some() {
+                out.reset()?;
+            }
+            write!(out, "{newline}")?;
+        }
+        write!(out, "{}{newline}", self.caption)?;
+        Ok(String::from_utf8_lossy(&out.into_inner()).into_owned())
+    }
+}
+
+/// Where frames go, and how fast.
+pub struct Player {
+    delay: Duration,
+    play: bool,
+    cast: Option<Cast>,
+    frames: usize,
+    /// The first error met while showing a frame, reported by [`finish`].
+    error: Option<anyhow::Error>,
+}
+
+/// An asciinema cast file (version 2) being written.
+struct Cast {
+    path: PathBuf,
+    out: BufWriter<File>,
+    has_header: bool,
+}
+
+impl Player {
+    /// A player showing `fps` frames per second, to nowhere until told otherwise.
+    pub fn new(fps: f64) -> Result<Self> {
+        let delay = Duration::try_from_secs_f64(1.0 / fps)
+            .ok()
+            .filter(|_| fps > 0.0)
+            .ok_or(anyhow::anyhow!("invalid frame rate {fps}"))?;
+        Ok(Self {
+            delay,
+            play: false,
+            cast: None,
+            frames: 0,
+            error: None,
+        })
+    }
+
+    /// Plays the frames on stderr.
+    pub fn play(mut self) -> Self {
+        self.play = true;
+        self
+    }
+
+    /// Records the frames to a cast file at `path`.
+    pub fn record(mut self, path: &Path) -> Result<Self> {
+        let file =
+            File::create(path).with_context(|| format!("could not create {}", path.display()))?;
+        self.cast = Some(Cast {
+            path: path.to_path_buf(),
+            out: BufWriter::new(file),
+            has_header: false,
+        });
+        Ok(self)
+    }
+
+    fn show(&mut self, frame: &Frame) -> Result<()> {
+        if self.play {
+            let mut stderr = io::stderr().lock();
+            write!(stderr, "{CLEAR}{}", frame.to_ansi("\n")?)?;
+            stderr.flush()?;
+            thread::sleep(self.delay);
+        }
+        if let Some(cast) = &mut self.cast {
+            // Frames are stamped with their rank rather than the time, so that recordings
+            // of the same run are the same.
+            let time = self.frames as f64 * self.delay.as_secs_f64();
+            cast.header(frame.width, frame.height + 1)?;
+            let event = serde_json::json!([time, "o", CLEAR.to_string() + &frame.to_ansi("\r\n")?]);
+            writeln!(cast.out, "{event}")
+                .with_context(|| format!("could not write {}", cast.path.display()))?;
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+impl Cast {
+    fn header(&mut self, width: usize, height: usize) -> Result<()> {
+        if !self.has_header {
+            let header = serde_json::json!({
+                "version": 2,
+                "width": width.max(1),
+                "height": height,
+                "env": { "TERM": "xterm-256color" },
+            });
+            writeln!(self.out, "{header}")
+                .with_context(|| format!("could not write {}", self.path.display()))?;
+            self.has_header = true;
+        }
+        Ok(())
+    }
+}
+
+static PLAYER: Mutex<Option<Player>> = Mutex::new(None);
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Sends the frames of the solvers to `player` from now on.
+pub fn start(player: Player) {
+    *PLAYER.lock().unwrap() = Some(player);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether frames are wanted, to skip the work of preparing them otherwise.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shows the frame built by `build`, which is only called when frames are wanted.
+pub fn frame(build: impl FnOnce() -> Frame) {
+    if !enabled() {
+        return;
+    }
+    let frame = build();
+    if let Some(player) = PLAYER.lock().unwrap().as_mut() {
+        if player.error.is_none() {
+            player.error = player.show(&frame).err();
+        }
+    }
+}
+
+/// Runs `f` without showing its frames, e.g. for a search whose result only is animated.
+pub fn without<T>(f: impl FnOnce() -> T) -> T {
+    let enabled = ENABLED.swap(false, Ordering::Relaxed);
+    let result = f();
+    ENABLED.store(enabled, Ordering::Relaxed);
+    result
+}
+
+/// Stops the player, returns how many frames it showed or the first error it met.
+pub fn finish() -> Result<usize> {
+    ENABLED.store(false, Ordering::Relaxed);
+    let Some(mut player) = PLAYER.lock().unwrap().take() else {
+        return Ok(0);
+    };
+    if let Some(err) = player.error {
+        return Err(err);
+    }
+    if let Some(cast) = &mut player.cast {
+        // An empty recording still needs its header.
+        cast.header(80, 24)?;
+        cast.out
+            .flush()
+            .with_context(|| format!("could not write {}", cast.path.display()))?;
+    }
+    Ok(player.frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new(3, 2).caption("step 1");
+        frame.set(Pos::new(0, 1), '#', Some(Color::Green));
+        frame.set(Pos::new(1, 2), 'O', None);
+        frame.set(Pos::new(2, 0), 'X', None);
+        assert_eq!(
+            " \x1b[0m\x1b[32m#\x1b[0m \n  O\nstep 1\n",
+            frame.to_ansi("\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day.cast");
+        assert!(Player::new(0.0).is_err());
+        start(Player::new(4.0).unwrap().record(&path).unwrap());
+
+        let mut built = 0;
+        for step in 0..3 {
+            frame(|| {
+                built += 1;
+                Frame::new(2, 1).caption(format!("step {step}"))
+            });
+        }
+        without(|| frame(|| panic!("frames are not wanted")));
+        assert_eq!(3, finish().unwrap());
+        assert_eq!(3, built);
+        frame(|| panic!("the player is stopped"));
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let lines = cast
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(4, lines.len());
+        assert_eq!(
+            (2, 2, 2),
+            (
+                lines[0]["version"].as_u64().unwrap(),
+                lines[0]["width"].as_u64().unwrap(),
+                lines[0]["height"].as_u64().unwrap()
+            )
+        );
+        assert_eq!(0.5, lines[3][0]);
+        assert_eq!("o", lines[3][1]);
+        assert_eq!(format!("{CLEAR}  \r\nstep 2\r\n"), lines[3][2]);
+    }
+}
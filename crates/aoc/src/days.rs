@@ -0,0 +1,227 @@
+use anyhow::Result;
+use render::Picture;
+use solution::{Part, Solver};
+
+use crate::params::Params;
+
+type SolverFn = fn(&Params) -> Result<Box<dyn Solver>>;
+type GenerateFn = fn(&mut generate::Rng, usize) -> String;
+type RenderFn = fn(&str, Part) -> Result<Picture>;
+
+pub struct Day {
+    pub number: u8,
+    /// Names of the parameters the day accepts through `--set`.
+    pub params: &'static [&'static str],
+    /// Parts too slow to be run on the full input by `verify`.
+    pub slow: &'static [Part],
+    /// Builds the day's solver, configured from the parameters.
+    pub solver: SolverFn,
+    /// Generates a random input of about the given size, for `aoc gen`.
+    pub generate: GenerateFn,
+    /// Draws a picture of the part's solution, for `--render`.
+    pub render: Option<RenderFn>,
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day01::Day01)),
+        generate: day01::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 2,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day02::Day02)),
+        generate: day02::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 3,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day03::Day03)),
+        generate: day03::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 4,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day04::Day04)),
+        generate: day04::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 5,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day05::Day05)),
+        generate: day05::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 6,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day06::Day06)),
+        generate: day06::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 7,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day07::Day07)),
+        generate: day07::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 8,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day08::Day08)),
+        generate: day08::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 9,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day09::Day09)),
+        generate: day09::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 10,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day10::Day10)),
+        generate: day10::generator::generate,
+        render: Some(|input, _| day10::picture(&input.parse()?)),
+    },
+    Day {
+        number: 11,
+        params: &["expansion"],
+        slow: &[],
+        solver: |params| {
+            let default = day11::Day11::default();
+            Ok(Box::new(day11::Day11 {
+                expansion: params.get_or("expansion", default.expansion)?,
+            }))
+        },
+        generate: day11::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 12,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day12::Day12)),
+        generate: day12::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 13,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day13::Day13)),
+        generate: day13::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 14,
+        params: &["cycles"],
+        slow: &[],
+        solver: |params| {
+            let default = day14::Day14::default();
+            Ok(Box::new(day14::Day14 {
+                cycles: params.get_or("cycles", default.cycles)?,
+            }))
+        },
+        generate: day14::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 15,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day15::Day15)),
+        generate: day15::generator::generate,
+        render: None,
+    },
+    Day {
+        number: 16,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day16::Day16)),
+        generate: day16::generator::generate,
+        render: Some(|input, part| Ok(day16::picture(&input.parse()?, part))),
+    },
+    Day {
+        number: 17,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day17::Day17)),
+        generate: day17::generator::generate,
+        render: Some(|input, part| day17::picture(&input.parse()?, part)),
+    },
+    Day {
+        number: 18,
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day18::Day18)),
+        generate: day18::generator::generate,
+        render: Some(|input, part| day18::picture(&day18::parse(input)?, part)),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use generate::Rng;
+
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for day in DAYS {
+            let parts = [Part::One, Part::Two]
+                .into_iter()
+                .filter(|part| !day.slow.contains(part))
+                .collect::<Vec<_>>();
+            let solver = (day.solver)(&Params::default()).unwrap();
+            for seed in 0..3 {
+                for size in [1, 5, 12] {
+                    let input = (day.generate)(&mut Rng::new(seed), size);
+                    assert_eq!(input, (day.generate)(&mut Rng::new(seed), size));
+                    if let Err(err) = solver.solve(&input, &parts) {
+                        panic!(
+                            "day {} failed on seed {seed} and size {size}: {:#}\n{input}",
+                            day.number,
+                            solution::explain(err, &input)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        for day in DAYS {
+            let Some(render) = day.render else {
+                continue;
+            };
+            let input = (day.generate)(&mut Rng::new(0), 8);
+            let picture = render(&input, Part::One).unwrap();
+            assert!(picture.width() > 0 && picture.height() > 0);
+        }
+    }
+}
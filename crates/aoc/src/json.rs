@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::{json, Value};
+use solution::Part;
+
+use crate::{
+    days::Day,
+    params::Params,
+    pool::{Report, Status},
+};
+
+/// One object per part with its answer and how long it took, or the error that prevented it.
+///
+/// Each part is solved on its own, so that a failing part does not hide the answer of the
+/// other one.
+pub fn records(day: &Day, params: &Params, input: &Result<String>, parts: &[Part]) -> Vec<Value> {
+    parts
+        .iter()
+        .map(|&part| {
+            let solved = input
+                .as_ref()
+                .map_err(|err| anyhow::anyhow!("{err:#}"))
+                .and_then(|input| {
+                    let solver = (day.solver)(params)?;
+                    solver
+                        .solve_timed(input, &[part])
+                        .map_err(|err| solution::explain(err, input))
+                });
+
+            match solved {
+                Ok((answers, timings)) => solved_record(
+                    day.number,
+                    part,
+                    &answers[0],
+                    timings.parse,
+                    timings.parts[0],
+                ),
+                Err(err) => failed_record(day.number, part, &format!("{err:#}")),
+            }
+        })
+        .collect()
+}
+
+/// The records of a day run by the pool, its parts sharing the parse time and the failure.
+pub fn report_records(report: &Report) -> Vec<Value> {
+    let error = match &report.status {
+        Status::Solved(answers, timings) => {
+            return report
+                .parts
+                .iter()
+                .zip(answers)
+                .zip(&timings.parts)
+                .map(|((&part, answer), &elapsed)| {
+                    solved_record(report.day, part, answer, timings.parse, elapsed)
+                })
+                .collect()
+        }
+        Status::Failed(err) | Status::Panicked(err) => err.clone(),
+        Status::TimedOut => format!("timed out after {:.2?}", report.elapsed),
+    };
+    report
+        .parts
+        .iter()
+        .map(|&part| failed_record(report.day, part, &error))
+        .collect()
+}
+
+fn solved_record(day: u8, part: Part, answer: &str, parse: Duration, elapsed: Duration) -> Value {
+    json!({
+        "day": day,
+        "part": part.number(),
+        "answer": answer,
+        "parse_seconds": parse.as_secs_f64(),
+        "seconds": elapsed.as_secs_f64(),
+        "error": null,
+    })
+}
+
+fn failed_record(day: u8, part: Part, error: &str) -> Value {
+    json!({
+        "day": day,
+        "part": part.number(),
+        "answer": null,
+        "parse_seconds": null,
+        "seconds": null,
+        "error": error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let day = crate::days::find(6).unwrap();
+        let input = Ok("Time:      7  15   30\nDistance:  9  40  200\n".to_string());
+        let solved = records(day, &Params::default(), &input, &[Part::One, Part::Two]);
+        assert_eq!(2, solved.len());
+        assert_eq!(json!("288"), solved[0]["answer"]);
+        assert_eq!(json!(2), solved[1]["part"]);
+        assert!(solved[1]["seconds"].is_f64());
+        assert!(solved[1]["error"].is_null());
+
+        let input = Ok("Time: 7\nDistance: x\n".to_string());
+        let failed = records(day, &Params::default(), &input, &[Part::One]);
+        assert!(failed[0]["answer"].is_null());
+        assert!(failed[0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("line 2, column 11: expected a number"));
+    }
+}
@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use solution::Part;
+
+/// Answers known to be right, keyed by day, part and hash of the input they were computed on.
+///
+/// It is stored as text, one `day part hash answer` entry per line, so that changes show up
+/// in diffs. The hash is truncated, see [`input_hash`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    entries: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`, a missing file being an empty ledger.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("invalid ledger {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut entries = BTreeMap::new();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, hash, answer] = fields[..] else {
+                return Err(anyhow::anyhow!("expected day part hash answer, got {line}"));
+            };
+            let part = Part::try_from(part.parse::<u8>()?)?;
+            entries.insert((day.parse()?, part, hash.to_string()), answer.to_string());
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part, hash: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, hash.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Records `answer`, replacing the one known for the same day, part and input.
+    pub fn record(&mut self, day: u8, part: Part, hash: &str, answer: &str) {
+        self.entries
+            .insert((day, part, hash.to_string()), answer.to_string());
+    }
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for ((day, part, hash), answer) in &self.entries {
+            writeln!(f, "{day:02} {} {hash} {answer}", part.number())?;
+        }
+        Ok(())
+    }
+}
+
+const HEADER: &str = "# day part input-hash answer\n\
+    # input-hash: the first 16 hex digits of the SHA-256 of the input";
+
+/// Identifies an input in the ledger: the first 16 hex digits of its SHA-256, which is plenty
+/// to tell a handful of inputs apart.
+pub fn input_hash(input: &str) -> String {
+    let digest = Sha256::digest(input.as_bytes());
+    digest[..8].iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger() {
+        let hash = input_hash("1abc2\n");
+        assert_eq!(16, hash.len());
+        assert_ne!(hash, input_hash("1abc3\n"));
+
+        let mut ledger = Ledger::default();
+        ledger.record(1, Part::Two, &hash, "142");
+        ledger.record(1, Part::One, &hash, "12");
+        ledger.record(1, Part::One, &hash, "142");
+        assert_eq!(Some("142"), ledger.get(1, Part::One, &hash));
+        assert_eq!(None, ledger.get(1, Part::One, "0000000000000000"));
+
+        let text = ledger.to_string();
+        assert_eq!(
+            format!("{HEADER}\n01 1 {hash} 142\n01 2 {hash} 142\n"),
+            text
+        );
+        assert_eq!(ledger, Ledger::parse(&text).unwrap());
+
+        assert!(Ledger::parse("01 3 abcd 12").is_err());
+        assert!(Ledger::parse("01 1 abcd").is_err());
+    }
+}
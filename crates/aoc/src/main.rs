@@ -0,0 +1,424 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use inputs::{Fetcher, Store};
+use solution::Part;
+
+use days::Day;
+use ledger::Ledger;
+use params::Params;
+
+mod days;
+mod json;
+mod ledger;
+mod params;
+#[cfg(test)]
+mod parsers;
+mod pool;
+#[cfg(test)]
+mod samples;
+mod scaffold;
+mod timing;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print the solvers' diagnostics, on stderr.
+    #[arg(short, long, global = true)]
+    verbose: bool,
+    #[command(flatten)]
+    store: StoreArgs,
+}
+
+/// Where the puzzle inputs are stored, and where the missing ones are fetched from.
+#[derive(Args)]
+struct StoreArgs {
+    /// Root of the input store, defaults to the `inputs` directory of the workspace.
+    #[arg(long = "inputs", global = true, env = inputs::ROOT_VAR, value_name = "DIR")]
+    root: Option<PathBuf>,
+    /// Whose inputs to use, each profile has its own directory in the store.
+    #[arg(long, global = true, env = inputs::PROFILE_VAR, default_value = inputs::DEFAULT_PROFILE)]
+    profile: String,
+    /// Server the inputs are fetched from, with the session cookie found in `AOC_SESSION`.
+    #[arg(long, global = true, env = inputs::BASE_URL_VAR, default_value = inputs::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl StoreArgs {
+    fn store(&self) -> Store {
+        let root = self.root.clone().unwrap_or_else(inputs::default_root);
+        Store::new(root, &self.profile)
+    }
+
+    fn fetcher(&self) -> Result<Fetcher> {
+        Ok(Fetcher::new(&self.base_url, inputs::session_from_env()?))
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of one or more days.
+    Run(RunArgs),
+    /// Run every day on its input and compare the answers to the ledger.
+    ///
+    /// Exits with an error on any mismatching, new or failed answer.
+    Verify {
+        /// Only verify these days.
+        days: Vec<u8>,
+        /// Record new and mismatching answers in the ledger instead of failing.
+        #[arg(long)]
+        update: bool,
+        /// Defaults to the `answers.txt` of the runner's crate.
+        #[arg(long)]
+        ledger: Option<PathBuf>,
+    },
+    /// Download inputs into the store.
+    Fetch {
+        #[arg(required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// Download the inputs already in the store again.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create the crate of a new day and register it in the runner and the benchmarks.
+    ///
+    /// An existing crate is never overwritten.
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle's title, for the crate's documentation.
+        #[arg(long, default_value = "TODO")]
+        title: String,
+    },
+    /// Print a random input for a day, the same one for the same seed and size.
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly the number of lines, or the side of the grid, of the input.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(required_unless_present = "all")]
+    days: Vec<u8>,
+    /// Run every day in parallel and print a summary, the parts too slow for the full input
+    /// are left out.
+    #[arg(long, conflicts_with_all = ["days", "input", "params", "time", "render", "animate", "cast"])]
+    all: bool,
+    /// Give up on a day after this many seconds, only with `--all`.
+    #[arg(long, value_name = "SECONDS", requires = "all")]
+    timeout: Option<f64>,
+    /// Only run this part, both parts are run otherwise.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input, `-` reads it from stdin. Defaults to the day's input in the store.
+    ///
+    /// Only for a single day.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Save a picture of the solution of the part, or of part 1, as an SVG or PNG image.
+    ///
+    /// Only for a single day, which must know how to draw itself.
+    #[arg(long, value_name = "PATH")]
+    render: Option<PathBuf>,
+    /// Play the solver's progress in the terminal, for the days that know how to.
+    ///
+    /// Only for a single day. The frames are drawn on stderr.
+    #[arg(long)]
+    animate: bool,
+    /// Frames per second of `--animate` and `--cast`.
+    #[arg(long, default_value_t = 20.0)]
+    fps: f64,
+    /// Record the solver's progress to an asciinema cast file.
+    ///
+    /// Only for a single day.
+    #[arg(long, value_name = "PATH")]
+    cast: Option<PathBuf>,
+    /// Fetch the inputs missing from the store instead of failing.
+    #[arg(long)]
+    fetch: bool,
+    /// Day-specific parameter, e.g. `expansion=10` for day 11.
+    #[arg(short = 'D', long = "set", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+    /// Print how long parsing and each part took, per day and in total.
+    ///
+    /// The JSON output always has the timings.
+    #[arg(long)]
+    time: bool,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per line and part, with the answer, the elapsed time and any error.
+    Json,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    solution::set_verbose(cli.verbose);
+
+    match cli.command {
+        Command::Run(args) => run(&args, &cli.store),
+        Command::Verify {
+            days,
+            update,
+            ledger,
+        } => verify(&days, update, ledger, &cli.store.store()),
+        Command::Fetch { days, force } => fetch(&days, force, &cli.store),
+        Command::New { day, title } => new(day, &title),
+        Command::Gen { day, seed, size } => generate(day, seed, size),
+    }
+}
+
+fn run(args: &RunArgs, store_args: &StoreArgs) -> Result<()> {
+    if args.all {
+        return run_all(args, store_args);
+    }
+    if args.input.is_some() && args.days.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "--input can only be given for a single day"
+        ));
+    }
+    if args.render.is_some() && args.days.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "--render can only be given for a single day"
+        ));
+    }
+    if (args.animate || args.cast.is_some()) && args.days.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "--animate and --cast can only be given for a single day"
+        ));
+    }
+
+    let params = Params::parse(&args.params)?;
+    let parts = parts(args)?;
+
+    let store = store_args.store();
+    let fetcher = if args.fetch && args.input.is_none() {
+        Some(store_args.fetcher()?)
+    } else {
+        None
+    };
+
+    if args.animate || args.cast.is_some() {
+        let mut player = animate::Player::new(args.fps)?;
+        if args.animate {
+            player = player.play();
+        }
+        if let Some(path) = &args.cast {
+            player = player.record(path)?;
+        }
+        animate::start(player);
+    }
+
+    let mut timings = Vec::new();
+    let mut failed = false;
+    for &number in &args.days {
+        let day = days::find(number).ok_or(anyhow::anyhow!("day {number} is not solved yet"))?;
+        if let Some(unknown) = params.keys().find(|key| !day.params.contains(key)) {
+            return Err(anyhow::anyhow!(
+                "day {number} does not take a {unknown} parameter (accepted: {:?})",
+                day.params
+            ));
+        }
+
+        let input = match (&args.input, &fetcher) {
+            (Some(path), _) => read_input(path),
+            (None, Some(fetcher)) => store.read_or_fetch(number, fetcher),
+            (None, None) => store.read(number),
+        };
+
+        if let (Some(path), Ok(input)) = (&args.render, &input) {
+            animate::without(|| render(day, input, parts[0], path))?;
+        }
+
+        if args.format == Format::Json {
+            for record in json::records(day, &params, &input, &parts) {
+                failed |= !record["error"].is_null();
+                println!("{record}");
+            }
+            continue;
+        }
+
+        let input = input?;
+        let solver = (day.solver)(&params)?;
+        let (answers, day_timings) = solver
+            .solve_timed(&input, &parts)
+            .map_err(|err| solution::explain(err, &input))?;
+        if args.days.len() > 1 {
+            println!("Day {number}");
+        }
+        for (part, answer) in parts.iter().zip(answers) {
+            println!("Part {}: {}", part.number(), answer);
+        }
+        timings.push((number, day_timings));
+    }
+
+    let frames = animate::finish()?;
+    if (args.animate || args.cast.is_some()) && frames == 0 {
+        eprintln!("warning: day {} shows no progress", args.days[0]);
+    }
+
+    if failed {
+        return Err(anyhow::anyhow!("some parts could not be solved"));
+    }
+    if args.time && args.format == Format::Text {
+        print!("\n{}", timing::table(&parts, &timings));
+    }
+
+    Ok(())
+}
+
+fn render(day: &Day, input: &str, part: Part, path: &Path) -> Result<()> {
+    let render = day
+        .render
+        .ok_or(anyhow::anyhow!("day {} cannot draw a picture", day.number))?;
+    let picture = render(input, part).map_err(|err| solution::explain(err, input))?;
+    picture.save(path)
+}
+
+fn run_all(args: &RunArgs, store_args: &StoreArgs) -> Result<()> {
+    let parts = parts(args)?;
+    let timeout = args
+        .timeout
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .context("invalid --timeout")?;
+    let store = store_args.store();
+    let fetcher = if args.fetch {
+        Some(store_args.fetcher()?)
+    } else {
+        None
+    };
+
+    let days = days::DAYS.iter().collect::<Vec<_>>();
+    let workers = thread::available_parallelism().map_or(1, usize::from);
+    let reports = pool::run_all(
+        &days,
+        &parts,
+        |number| match &fetcher {
+            Some(fetcher) => store.read_or_fetch(number, fetcher),
+            None => store.read(number),
+        },
+        timeout,
+        workers,
+    );
+
+    match args.format {
+        Format::Text => print!("{}", pool::summary(&reports)),
+        Format::Json => {
+            for record in reports.iter().flat_map(json::report_records) {
+                println!("{record}");
+            }
+        }
+    }
+    if !reports.iter().all(pool::Report::is_success) {
+        return Err(anyhow::anyhow!("some days could not be solved"));
+    }
+
+    Ok(())
+}
+
+fn parts(args: &RunArgs) -> Result<Vec<Part>> {
+    Ok(match args.part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => vec![Part::One, Part::Two],
+    })
+}
+
+fn verify(numbers: &[u8], update: bool, ledger_path: Option<PathBuf>, store: &Store) -> Result<()> {
+    let days = if numbers.is_empty() {
+        days::DAYS.iter().collect()
+    } else {
+        numbers
+            .iter()
+            .map(|&number| {
+                days::find(number).ok_or(anyhow::anyhow!("day {number} is not solved yet"))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let ledger_path =
+        ledger_path.unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.txt"));
+    let mut ledger = Ledger::load(&ledger_path)?;
+
+    let outcomes = verify::verify(&days, &mut ledger, update, |number| store.read(number));
+    let differs = verify::report(&outcomes, &ledger_path);
+
+    if update {
+        ledger.save(&ledger_path)?;
+    }
+    let failed = outcomes
+        .iter()
+        .any(|(_, _, outcome)| matches!(outcome, verify::Outcome::Failed(_)));
+    if failed || (differs && !update) {
+        return Err(anyhow::anyhow!("the answers differ from the ledger"));
+    }
+
+    Ok(())
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    std::fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))
+}
+
+fn fetch(numbers: &[u8], force: bool, store_args: &StoreArgs) -> Result<()> {
+    let store = store_args.store();
+    let fetcher = store_args.fetcher()?;
+    for &number in numbers {
+        if store.contains(number) && !force {
+            println!("day {number}: already in {}", store.path(number).display());
+            continue;
+        }
+        let input = fetcher.fetch(number)?;
+        println!(
+            "day {number}: saved to {}",
+            store.save(number, &input)?.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn new(day: u8, title: &str) -> Result<()> {
+    let root = scaffold::workspace_root();
+    for path in scaffold::new_day(&root, day, title)? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    println!(
+        "save the sample of the puzzle in crates/day{day:02}/sample.txt and its answers next to it"
+    );
+
+    Ok(())
+}
+
+fn generate(number: u8, seed: u64, size: usize) -> Result<()> {
+    let day = days::find(number).ok_or(anyhow::anyhow!("day {number} is not solved yet"))?;
+    print!("{}", (day.generate)(&mut generate::Rng::new(seed), size));
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+
+/// Day-specific knobs passed on the command line as `--set key=value`.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn parse(args: &[String]) -> Result<Self> {
+        let values = args
+            .iter()
+            .map(|arg| {
+                let (key, value) = arg
+                    .split_once('=')
+                    .ok_or(anyhow::anyhow!("expected key=value, got {arg}"))?;
+                Ok((key.to_string(), value.to_string()))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        Ok(Self { values })
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Returns the value for `key`, or `default` when it was not given.
+    pub fn get_or<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.values.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|err| anyhow::anyhow!("invalid value for {key}: {err}")),
+            None => Ok(default),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params() {
+        let params = Params::parse(&["expansion=10".to_string()]).unwrap();
+        assert_eq!(params.get_or("expansion", 2usize).unwrap(), 10);
+        assert_eq!(params.get_or("cycles", 3usize).unwrap(), 3);
+
+        assert!(Params::parse(&["expansion".to_string()]).is_err());
+        let params = Params::parse(&["expansion=ten".to_string()]).unwrap();
+        assert!(params.get_or("expansion", 2usize).is_err());
+    }
+}
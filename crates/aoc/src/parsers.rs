@@ -0,0 +1,144 @@
+//! Checks that no day's parser panics, whatever the input.
+//!
+//! Parsers get arbitrary text, and generated inputs with a few chars edited, which get
+//! deeper into the parsers than random text does. The `fuzz` directory at the root of the
+//! workspace has the same checks as `cargo fuzz` targets.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use proptest::{collection::vec, prelude::*, sample::select};
+
+use crate::{
+    days::{Day, DAYS},
+    params::Params,
+    pool::panic_message,
+};
+
+/// The chars the puzzle formats are made of, so that random text looks like an input.
+const PUZZLE_CHARS: &str = "0123456789 \n:,;|=-#.?OSLJF7()RDUAKQT";
+
+fn puzzle_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        4 => select(PUZZLE_CHARS.chars().collect::<Vec<_>>()),
+        1 => any::<char>(),
+    ]
+}
+
+/// Replaces, inserts or removes a char at a position, taken modulo the length of the text.
+#[derive(Debug, Clone)]
+enum Edit {
+    Replace(usize, char),
+    Insert(usize, char),
+    Remove(usize),
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<usize>(), puzzle_char()).prop_map(|(at, ch)| Edit::Replace(at, ch)),
+        (any::<usize>(), puzzle_char()).prop_map(|(at, ch)| Edit::Insert(at, ch)),
+        any::<usize>().prop_map(Edit::Remove),
+    ]
+}
+
+fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+    for edit in edits {
+        match *edit {
+            Edit::Replace(at, ch) if !chars.is_empty() => {
+                let at = at % chars.len();
+                chars[at] = ch;
+            }
+            Edit::Insert(at, ch) => chars.insert(at % (chars.len() + 1), ch),
+            Edit::Remove(at) if !chars.is_empty() => {
+                chars.remove(at % chars.len());
+            }
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Parses `input` with the day, errors are fine but panics are not.
+fn parse(day: &Day, input: &str) -> Result<(), TestCaseError> {
+    let solver = (day.solver)(&Params::default()).unwrap();
+    panic::catch_unwind(AssertUnwindSafe(|| solver.solve(input, &[])))
+        .map(|_| ())
+        .map_err(|payload| {
+            TestCaseError::fail(format!(
+                "day {} {} on {input:?}",
+                day.number,
+                panic_message(payload.as_ref())
+            ))
+        })
+}
+
+/// Every `FromStr` of the days, by name.
+macro_rules! from_str {
+    ($($ty:ty),* $(,)?) => {
+        &[$((stringify!($ty), |s: &str| drop(s.parse::<$ty>()))),*]
+    };
+}
+
+/// Parses a string, dropping the result.
+type Parse = fn(&str);
+
+const FROM_STR: &[(&str, Parse)] = from_str![
+    day03::Schematic,
+    day04::Card,
+    day04::Input,
+    day07::Play,
+    day07::Hand,
+    day07::Card,
+    day07::JokerCard,
+    day07::JokerHand,
+    day07::JokerPlay,
+    day10::Labyrinth,
+    day12::Line,
+    day13::Map,
+    day14::Map,
+    day15::Instruction,
+    day16::Map,
+    day17::Map,
+];
+
+proptest! {
+    #[test]
+    fn test_from_str(input in vec(puzzle_char(), 0..40)) {
+        let input = input.into_iter().collect::<String>();
+        for (name, parse) in FROM_STR {
+            panic::catch_unwind(|| parse(&input)).map_err(|payload| {
+                TestCaseError::fail(format!(
+                    "{name} {} on {input:?}",
+                    panic_message(payload.as_ref())
+                ))
+            })?;
+        }
+    }
+
+    #[test]
+    fn test_parsers_on_text(input in vec(puzzle_char(), 0..200)) {
+        let input = input.into_iter().collect::<String>();
+        for day in DAYS {
+            parse(day, &input)?;
+        }
+    }
+
+    #[test]
+    fn test_parsers_on_edited_inputs(
+        seed in any::<u64>(),
+        size in 1..12usize,
+        edits in vec(edit(), 1..6),
+    ) {
+        for day in DAYS {
+            let input = (day.generate)(&mut generate::Rng::new(seed), size);
+            parse(day, &apply(&input, &edits))?;
+        }
+    }
+}
+
+#[test]
+fn test_apply() {
+    let edits = [Edit::Replace(5, 'x'), Edit::Insert(0, 'y'), Edit::Remove(2)];
+    assert_eq!("yax", apply("abc", &edits));
+    assert_eq!("", apply("", &edits));
+}
@@ -0,0 +1,260 @@
+use std::{
+    any::Any,
+    fmt::Write,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use solution::{Part, Timings};
+
+use crate::{days::Day, params::Params};
+
+#[derive(Debug)]
+pub enum Status {
+    Solved(Vec<String>, Timings),
+    /// The input could not be read, or the solver returned an error.
+    Failed(String),
+    Panicked(String),
+    /// The day was given up on, its thread is left running until the runner exits.
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    /// The parts that were run, the ones too slow for the day are left out.
+    pub parts: Vec<Part>,
+    pub status: Status,
+    /// Wall time from the start of the day to its answers, or to giving up on it.
+    pub elapsed: Duration,
+}
+
+impl Report {
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, Status::Solved(..))
+    }
+}
+
+/// Runs `parts` of every day on `workers` threads, one day never bringing the others down.
+///
+/// Days running longer than `timeout` are reported as such. The reports are sorted by day.
+pub fn run_all(
+    days: &[&'static Day],
+    parts: &[Part],
+    input_of: impl Fn(u8) -> Result<String> + Sync,
+    timeout: Option<Duration>,
+    workers: usize,
+) -> Vec<Report> {
+    let queue = Mutex::new(days.iter().copied());
+    let reports = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1) {
+            scope.spawn(|| loop {
+                let Some(day) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let report = run_day(day, parts, input_of(day.number), timeout);
+                reports.lock().unwrap().push(report);
+            });
+        }
+    });
+
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|report| report.day);
+    reports
+}
+
+fn run_day(
+    day: &'static Day,
+    parts: &[Part],
+    input: Result<String>,
+    timeout: Option<Duration>,
+) -> Report {
+    let start = Instant::now();
+    let parts = parts
+        .iter()
+        .filter(|part| !day.slow.contains(part))
+        .copied()
+        .collect::<Vec<_>>();
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            return Report {
+                day: day.number,
+                parts,
+                status: Status::Failed(format!("{err:#}")),
+                elapsed: start.elapsed(),
+            }
+        }
+    };
+
+    // The solver gets a thread of its own, which can be left behind when it times out.
+    let (sender, receiver) = mpsc::channel();
+    let solved_parts = parts.clone();
+    thread::spawn(move || {
+        // Panics are part of the reports, not printed over the other days' output.
+        let result = solution::catch_silently(|| {
+            let solver = (day.solver)(&Params::default())?;
+            solver
+                .solve_timed(&input, &solved_parts)
+                .map_err(|err| solution::explain(err, &input))
+        });
+        let _ = sender.send(result);
+    });
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    let status = match received {
+        Ok(Ok(Ok((answers, timings)))) => Status::Solved(answers, timings),
+        Ok(Ok(Err(err))) => Status::Failed(format!("{err:#}")),
+        Ok(Err(payload)) => Status::Panicked(panic_message(payload.as_ref())),
+        Err(RecvTimeoutError::Timeout) => Status::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => Status::Panicked("the solver died".to_string()),
+    };
+
+    Report {
+        day: day.number,
+        parts,
+        status,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// The message a panic was raised with, when it has one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(msg) => format!("panicked: {msg}"),
+        None => match payload.downcast_ref::<String>() {
+            Some(msg) => format!("panicked: {msg}"),
+            None => "panicked".to_string(),
+        },
+    }
+}
+
+/// One line per day with its answers, how long it took and how it ended.
+pub fn summary(reports: &[Report]) -> String {
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "{:>5} {:>16} {:>16} {:>10}  status",
+        "day", "part 1", "part 2", "time"
+    );
+
+    for report in reports {
+        let answer = |wanted: Part| match &report.status {
+            Status::Solved(answers, _) => report
+                .parts
+                .iter()
+                .position(|part| *part == wanted)
+                .map(|i| answers[i].clone()),
+            _ => None,
+        };
+        let status = match &report.status {
+            Status::Solved(..) => "ok".to_string(),
+            Status::Failed(err) => format!("failed: {}", first_line(err)),
+            Status::Panicked(msg) => first_line(msg).to_string(),
+            Status::TimedOut => "timed out".to_string(),
+        };
+        let _ = writeln!(
+            table,
+            "{:>5} {:>16} {:>16} {:>10}  {status}",
+            report.day,
+            answer(Part::One).unwrap_or_else(|| "-".to_string()),
+            answer(Part::Two).unwrap_or_else(|| "-".to_string()),
+            format!("{:.2?}", report.elapsed),
+        );
+    }
+
+    let solved = reports.iter().filter(|report| report.is_success()).count();
+    let _ = writeln!(table, "\n{solved}/{} days solved", reports.len());
+    table
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use solution::Solution;
+
+    use super::*;
+
+    /// Part 1 answers, part 2 panics and the sample of day 1 takes forever.
+    struct Flaky;
+
+    impl Solution for Flaky {
+        type Input = String;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            if input == "sleep" {
+                thread::sleep(Duration::from_secs(60));
+            }
+            Ok(input.to_string())
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<usize> {
+            panic!("part 2 is broken")
+        }
+    }
+
+    fn flaky(number: u8) -> &'static Day {
+        Box::leak(Box::new(Day {
+            number,
+            params: &[],
+            slow: &[],
+            solver: |_| Ok(Box::new(Flaky)),
+            generate: |_, _| String::new(),
+            render: None,
+        }))
+    }
+
+    #[test]
+    fn test_run_all() {
+        let days = [flaky(3), flaky(1), flaky(2), flaky(4)];
+        let input_of = |number| match number {
+            1 => Ok("sleep".to_string()),
+            4 => Err(anyhow::anyhow!("no input")),
+            _ => Ok("abc".to_string()),
+        };
+
+        let reports = run_all(
+            &days,
+            &[Part::One],
+            input_of,
+            Some(Duration::from_millis(200)),
+            2,
+        );
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            reports.iter().map(|r| r.day).collect::<Vec<_>>()
+        );
+        assert!(matches!(reports[0].status, Status::TimedOut));
+        assert!(matches!(&reports[1].status, Status::Solved(answers, _) if answers == &["3"]));
+        assert!(matches!(&reports[3].status, Status::Failed(err) if err == "no input"));
+
+        // Without day 1, which would sleep for the whole minute with no timeout.
+        let days = [flaky(3), flaky(2)];
+        let reports = run_all(&days, &[Part::One, Part::Two], input_of, None, 4);
+        assert!(
+            matches!(&reports[0].status, Status::Panicked(msg) if msg == "panicked: part 2 is broken")
+        );
+
+        let table = summary(&reports);
+        assert!(table.contains("panicked: part 2 is broken"));
+        assert!(table.ends_with("\n0/2 days solved\n"));
+    }
+}
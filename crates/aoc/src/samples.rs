@@ -0,0 +1,111 @@
+//! Runs every day on the sample inputs checked in next to its crate.
+//!
+//! Each `sample*.txt` comes with a `sample*.answers` sidecar holding the
+//! expected answers, one `partN = answer` per line. Parts that make no sense
+//! for a sample are left out, and `#` starts a comment.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use solution::Part;
+
+use crate::{days::DAYS, params::Params};
+
+/// The directory of the day's crate, e.g. `crates/day07`.
+fn day_dir(number: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{number:02}"))
+}
+
+struct Sample {
+    input: PathBuf,
+    expected: Vec<(Part, String)>,
+}
+
+/// Every sample of the day's directory, each one must have its answers.
+fn samples(dir: &Path) -> Result<Vec<Sample>> {
+    let mut samples = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let input = entry?.path();
+        let name = input
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if !(name.starts_with("sample") && name.ends_with(".txt")) {
+            continue;
+        }
+
+        let answers = input.with_extension("answers");
+        let text = fs::read_to_string(&answers)
+            .with_context(|| format!("could not read {}", answers.display()))?;
+        let expected = parse_answers(&text).with_context(|| format!("in {}", answers.display()))?;
+        samples.push(Sample { input, expected });
+    }
+
+    samples.sort_by(|a, b| a.input.cmp(&b.input));
+    Ok(samples)
+}
+
+fn parse_answers(text: &str) -> Result<Vec<(Part, String)>> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (key, answer) = line
+                .split_once('=')
+                .ok_or(anyhow::anyhow!("expected partN = answer, got {line}"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                other => return Err(anyhow::anyhow!("there is no {other}")),
+            };
+            Ok((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = parse_answers("# only part 2\n\npart2 = 6\n").unwrap();
+    assert_eq!(vec![(Part::Two, "6".to_string())], answers);
+
+    assert!(parse_answers("part3 = 1").is_err());
+    assert!(parse_answers("part1: 1").is_err());
+}
+
+#[test]
+fn test_samples() {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for day in DAYS {
+        for sample in samples(&day_dir(day.number)).unwrap() {
+            let input = fs::read_to_string(&sample.input).unwrap();
+            let (parts, expected): (Vec<_>, Vec<_>) = sample.expected.into_iter().unzip();
+            let solver = (day.solver)(&Params::default()).unwrap();
+
+            match solver.solve(&input, &parts) {
+                Ok(answers) => {
+                    for ((part, expected), answer) in parts.iter().zip(expected).zip(answers) {
+                        if answer != expected {
+                            failures.push(format!(
+                                "{} part {}: expected {expected}, got {answer}",
+                                sample.input.display(),
+                                part.number()
+                            ));
+                        }
+                    }
+                }
+                Err(err) => failures.push(format!("{}: {err:#}", sample.input.display())),
+            }
+            checked += 1;
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(checked > 0, "no sample found");
+}
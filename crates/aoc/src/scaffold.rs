@@ -0,0 +1,213 @@
+//! `aoc new`: a crate for a new day, from the templates next to the runner's crate, and
+//! its registration in the runner and the benchmarks.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.in")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.in")),
+    (
+        "src/generator.rs",
+        include_str!("../templates/generator.rs.in"),
+    ),
+    ("src/main.rs", include_str!("../templates/main.rs.in")),
+    ("sample.txt", ""),
+    (
+        "sample.answers",
+        include_str!("../templates/sample.answers.in"),
+    ),
+];
+
+/// Adds a day to the text of a file, failing if it is already there.
+type Register = fn(&str, u8) -> Result<String>;
+
+/// The root of the workspace the runner is built from.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("..")
+}
+
+/// Creates `crates/dayNN` under `root` and registers it, returns the files written.
+///
+/// Nothing is written when the crate already exists or the day cannot be registered.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    let dir = root.join("crates").join(format!("day{day:02}"));
+    if dir.exists() {
+        return Err(anyhow::anyhow!(
+            "crates/day{day:02} already exists, not overwriting it"
+        ));
+    }
+
+    let registrations: [(&str, Register); 4] = [
+        ("crates/aoc/Cargo.toml", register_dependency),
+        ("crates/aoc/src/days.rs", register_day),
+        ("crates/bench/Cargo.toml", register_dependency),
+        ("crates/bench/benches/days.rs", register_bench),
+    ];
+    let mut changes = Vec::new();
+    for (file, register) in registrations {
+        let path = root.join(file);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        let text = register(&text, day).with_context(|| format!("in {}", path.display()))?;
+        changes.push((path, text));
+    }
+
+    let mut written = Vec::new();
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("could not create {}", parent.display()))?;
+        }
+        fs::write(&path, render(template, day, title))
+            .with_context(|| format!("could not write {}", path.display()))?;
+        written.push(path);
+    }
+    for (path, text) in changes {
+        fs::write(&path, text).with_context(|| format!("could not write {}", path.display()))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{{nn}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{title}}", title)
+}
+
+/// Adds the day's crate to the dependencies of a manifest.
+fn register_dependency(manifest: &str, day: u8) -> Result<String> {
+    let entry = format!("day{day:02} = {{ path = \"../day{day:02}\" }}");
+    insert_line(manifest, day, &entry, |line| {
+        line.strip_prefix("day")?.split_once(" = ")?.0.parse().ok()
+    })
+}
+
+/// Adds the day to the runner's [`DAYS`](crate::days::DAYS).
+fn register_day(days: &str, day: u8) -> Result<String> {
+    let entry = render(
+        "    Day {
+        number: {{day}},
+        params: &[],
+        slow: &[],
+        solver: |_| Ok(Box::new(day{{nn}}::Day{{nn}})),
+        generate: day{{nn}}::generator::generate,
+        render: None,
+    },",
+        day,
+        "",
+    );
+    // Entries are told apart by their number, and end with the line closing them.
+    let mut number = None;
+    insert_line(days, day, &entry, |line| {
+        if let Some(n) = line.trim().strip_prefix("number: ") {
+            number = n.trim_end_matches(',').parse().ok();
+        }
+        if line == "    }," {
+            number.take()
+        } else {
+            None
+        }
+    })
+}
+
+/// Adds the day to the benchmarks.
+fn register_bench(bench: &str, day: u8) -> Result<String> {
+    let entry = format!("    bench_day(c, {day}, day{day:02}::Day{day:02}, &[]);");
+    insert_line(bench, day, &entry, |line| {
+        line.trim()
+            .strip_prefix("bench_day(c, ")?
+            .split_once(',')?
+            .0
+            .parse()
+            .ok()
+    })
+}
+
+/// Inserts `entry` after the last line of the days before `day`, or before the first day.
+///
+/// `day_of` is called on each line in order and tells which day the line ends the entry of.
+fn insert_line(
+    text: &str,
+    day: u8,
+    entry: &str,
+    mut day_of: impl FnMut(&str) -> Option<u8>,
+) -> Result<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(anyhow::anyhow!("day {day} is already registered"));
+    }
+
+    let at = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => days
+            .first()
+            .map(|&(i, _)| i)
+            .ok_or(anyhow::anyhow!("found no day to put day {day} next to"))?,
+    };
+    lines.insert(at, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        for file in [
+            "crates/aoc/Cargo.toml",
+            "crates/aoc/src/days.rs",
+            "crates/bench/Cargo.toml",
+            "crates/bench/benches/days.rs",
+        ] {
+            let path = root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(workspace_root().join(file), path).unwrap();
+        }
+
+        let written = new_day(root.path(), 25, "Snowverload").unwrap();
+        assert_eq!(10, written.len());
+        let read = |file: &str| fs::read_to_string(root.path().join(file)).unwrap();
+        assert!(read("crates/day25/src/lib.rs").starts_with("//! Day 25: Snowverload\n"));
+        assert!(read("crates/day25/src/main.rs").contains("&day25::Day25, "));
+        assert!(read("crates/aoc/Cargo.toml")
+            .contains("day18 = { path = \"../day18\" }\nday25 = { path = \"../day25\" }\n"));
+        assert!(read("crates/aoc/src/days.rs")
+            .contains("day25::generator::generate,\n        render: None,\n    },\n];\n"));
+        assert!(read("crates/bench/benches/days.rs")
+            .contains("day18::Day18, &[]);\n    bench_day(c, 25, day25::Day25, &[]);\n}"));
+
+        let err = new_day(root.path(), 25, "Snowverload").unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_insert_line() {
+        let manifest = "[dependencies]\nday02 = {}\nday05 = {}\ninputs = {}\n";
+        assert_eq!(
+            "[dependencies]\nday01 = { path = \"../day01\" }\nday02 = {}\nday05 = {}\ninputs = {}\n",
+            register_dependency(manifest, 1).unwrap()
+        );
+        assert_eq!(
+            "[dependencies]\nday02 = {}\nday03 = { path = \"../day03\" }\nday05 = {}\ninputs = {}\n",
+            register_dependency(manifest, 3).unwrap()
+        );
+        assert!(register_dependency(manifest, 5).is_err());
+        assert!(register_dependency("[dependencies]\n", 5).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use std::{fmt::Write, time::Duration};
+
+use solution::{Part, Timings};
+
+/// A table of the time spent in each phase of every day, with a total per day and overall.
+pub fn table(parts: &[Part], days: &[(u8, Timings)]) -> String {
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "{:>5} {:>10} {:>10} {:>10} {:>10}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+
+    for (day, timings) in days {
+        let part = |wanted: Part| {
+            parts
+                .iter()
+                .position(|part| *part == wanted)
+                .map(|i| format_duration(timings.parts[i]))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let _ = writeln!(
+            table,
+            "{day:>5} {:>10} {:>10} {:>10} {:>10}",
+            format_duration(timings.parse),
+            part(Part::One),
+            part(Part::Two),
+            format_duration(timings.total())
+        );
+    }
+
+    let total = days.iter().map(|(_, timings)| timings.total()).sum();
+    let _ = writeln!(table, "{:>5} {:>43}", "total", format_duration(total));
+    table
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let timings = Timings {
+            parse: Duration::from_micros(250),
+            parts: vec![Duration::from_millis(3)],
+        };
+        let table = table(&[Part::Two], &[(7, timings.clone()), (11, timings)]);
+        assert_eq!(
+            "  day      parse     part 1     part 2      total\n    \
+                 7   250.00µs          -     3.00ms     3.25ms\n   \
+                11   250.00µs          -     3.00ms     3.25ms\n\
+             total                                      6.50ms\n",
+            table
+        );
+    }
+}
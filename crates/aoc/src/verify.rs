@@ -0,0 +1,219 @@
+use std::path::Path;
+
+use anyhow::Result;
+use solution::Part;
+
+use crate::{
+    days::Day,
+    ledger::{input_hash, Ledger},
+    params::Params,
+    pool::panic_message,
+};
+
+/// How an answer compares to the ledger.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+        got: String,
+    },
+    /// The ledger knows nothing about this input yet.
+    New(String),
+    /// The solver returned an error or panicked.
+    Failed(String),
+    /// Too slow to be run on the full input, see [`Day::slow`].
+    Skipped,
+}
+
+impl Outcome {
+    /// Whether `verify` must fail because of this outcome.
+    pub fn is_difference(&self) -> bool {
+        !matches!(self, Outcome::Match | Outcome::Skipped)
+    }
+}
+
+/// Runs every part of `days` on its input and compares the answers to `ledger`.
+///
+/// New and mismatching answers are recorded in `ledger` when `update` is set.
+pub fn verify(
+    days: &[&Day],
+    ledger: &mut Ledger,
+    update: bool,
+    input_of: impl Fn(u8) -> Result<String>,
+) -> Vec<(u8, Part, Outcome)> {
+    let mut outcomes = Vec::new();
+    for day in days {
+        let input = input_of(day.number);
+        for part in [Part::One, Part::Two] {
+            let outcome = match &input {
+                _ if day.slow.contains(&part) => Outcome::Skipped,
+                Ok(input) => check(day, part, input, ledger, update),
+                Err(err) => Outcome::Failed(format!("{err:#}")),
+            };
+            outcomes.push((day.number, part, outcome));
+        }
+    }
+
+    outcomes
+}
+
+fn check(day: &Day, part: Part, input: &str, ledger: &mut Ledger, update: bool) -> Outcome {
+    let answer = match solve(day, part, input) {
+        Ok(answer) => answer,
+        Err(err) => return Outcome::Failed(err),
+    };
+
+    let hash = input_hash(input);
+    let outcome = match ledger.get(day.number, part, &hash) {
+        Some(expected) if expected == answer => return Outcome::Match,
+        Some(expected) => Outcome::Mismatch {
+            expected: expected.to_string(),
+            got: answer.clone(),
+        },
+        None => Outcome::New(answer.clone()),
+    };
+    if update {
+        ledger.record(day.number, part, &hash, &answer);
+    }
+    outcome
+}
+
+fn solve(day: &Day, part: Part, input: &str) -> Result<String, String> {
+    // Panics are reported along with the other outcomes, not on stderr.
+    let result = solution::catch_silently(|| {
+        let solver = (day.solver)(&Params::default())?;
+        solver.solve(input, &[part])
+    });
+
+    match result {
+        Ok(Ok(mut answers)) => Ok(answers.remove(0)),
+        Ok(Err(err)) => Err(format!("{:#}", solution::explain(err, input))),
+        Err(payload) => Err(panic_message(payload.as_ref())),
+    }
+}
+
+/// Prints one line per part then a summary, returns whether there was any difference.
+pub fn report(outcomes: &[(u8, Part, Outcome)], ledger: &Path) -> bool {
+    let mut counts = [0; 5];
+    for (day, part, outcome) in outcomes {
+        let (index, status) = match outcome {
+            Outcome::Match => (0, "ok".to_string()),
+            Outcome::Mismatch { expected, got } => {
+                (1, format!("MISMATCH expected {expected}, got {got}"))
+            }
+            Outcome::New(answer) => (2, format!("NEW {answer}")),
+            Outcome::Failed(err) => (3, format!("FAILED {err}")),
+            Outcome::Skipped => (4, "skipped".to_string()),
+        };
+        counts[index] += 1;
+        println!("day {day:02} part {}: {status}", part.number());
+    }
+
+    let [ok, mismatches, new, failed, skipped] = counts;
+    println!(
+        "\n{ok} ok, {mismatches} mismatched, {new} new, {failed} failed, {skipped} skipped (ledger: {})",
+        ledger.display()
+    );
+    outcomes
+        .iter()
+        .any(|(_, _, outcome)| outcome.is_difference())
+}
+
+#[cfg(test)]
+mod tests {
+    use solution::Solution;
+
+    use super::*;
+
+    fn day(number: u8) -> &'static Day {
+        crate::days::find(number).unwrap()
+    }
+
+    /// Part 1 panics, part 2 answers with the length of the input.
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = String;
+        type Part1 = usize;
+        type Part2 = usize;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            Ok(input.to_string())
+        }
+
+        fn part1(&self, _input: &Self::Input) -> Result<usize> {
+            panic!("part 1 is broken")
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<usize> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let sample = "Time:      7  15   30\nDistance:  9  40  200\n".to_string();
+        let hash = input_hash(&sample);
+        let mut ledger = Ledger::default();
+        ledger.record(6, Part::One, &hash, "288");
+        ledger.record(6, Part::Two, &hash, "71502");
+
+        let outcomes = verify(&[day(6)], &mut ledger, false, |_| Ok(sample.clone()));
+        assert_eq!(
+            vec![
+                (6, Part::One, Outcome::Match),
+                (
+                    6,
+                    Part::Two,
+                    Outcome::Mismatch {
+                        expected: "71502".to_string(),
+                        got: "71503".to_string()
+                    }
+                ),
+            ],
+            outcomes
+        );
+        assert_eq!(Some("71502"), ledger.get(6, Part::Two, &hash));
+
+        verify(&[day(6)], &mut ledger, true, |_| Ok(sample.clone()));
+        assert_eq!(Some("71503"), ledger.get(6, Part::Two, &hash));
+
+        let broken = Day {
+            number: 1,
+            params: &[],
+            slow: &[],
+            solver: |_| Ok(Box::new(Broken)),
+            generate: |_, _| String::new(),
+            render: None,
+        };
+        let outcomes = verify(&[&broken], &mut Ledger::default(), false, |_| {
+            Ok("abc".to_string())
+        });
+        assert_eq!(
+            Outcome::Failed("panicked: part 1 is broken".to_string()),
+            outcomes[0].2
+        );
+        assert_eq!(Outcome::New("3".to_string()), outcomes[1].2);
+
+        // Day 1 errors on a line without digits.
+        let outcomes = verify(&[day(1)], &mut Ledger::default(), false, |_| {
+            Ok("eightwothree\n".to_string())
+        });
+        assert_eq!(
+            Outcome::Failed("line 1 has no digit: eightwothree".to_string()),
+            outcomes[0].2
+        );
+        assert_eq!(Outcome::New("83".to_string()), outcomes[1].2);
+
+        let slow = Day {
+            slow: &[Part::Two],
+            ..broken
+        };
+        let outcomes = verify(&[&slow], &mut Ledger::default(), false, |_| {
+            Err(anyhow::anyhow!("no input"))
+        });
+        assert_eq!(Outcome::Failed("no input".to_string()), outcomes[0].2);
+        assert_eq!(Outcome::Skipped, outcomes[1].2);
+    }
+}
@@ -0,0 +1,26 @@
+use bench::bench_day;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    bench_day(c, 1, day01::Day01, &[]);
+    bench_day(c, 2, day02::Day02, &[]);
+    bench_day(c, 3, day03::Day03, &[]);
+    bench_day(c, 4, day04::Day04, &[]);
+    bench_day(c, 5, day05::Day05, &[]);
+    bench_day(c, 6, day06::Day06, &[]);
+    bench_day(c, 7, day07::Day07, &[]);
+    bench_day(c, 8, day08::Day08, &[]);
+    bench_day(c, 9, day09::Day09, &[]);
+    bench_day(c, 10, day10::Day10, &[]);
+    bench_day(c, 11, day11::Day11::default(), &[]);
+    bench_day(c, 12, day12::Day12, &[]);
+    bench_day(c, 13, day13::Day13, &[]);
+    bench_day(c, 14, day14::Day14::default(), &[]);
+    bench_day(c, 15, day15::Day15, &[]);
+    bench_day(c, 16, day16::Day16, &[]);
+    bench_day(c, 17, day17::Day17, &[]);
+    bench_day(c, 18, day18::Day18, &[]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+//! Criterion benchmarks of every day, run them with `cargo bench -p bench`.
+//!
+//! Parsing and both parts are measured separately, e.g. `cargo bench -p bench -- day17/part2`
+//! only runs the second part of day 17.
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+use solution::{Part, Solution};
+
+/// Benchmarks the parsing and each part of `solution` on the day's input, in a `dayNN` group.
+///
+/// The input comes from the store selected by the `AOC_INPUTS` and `AOC_PROFILE` variables.
+///
+/// The parts in `skip` are left out, some take far too long to be sampled.
+pub fn bench_day<S: Solution>(c: &mut Criterion, day: u8, solution: S, skip: &[Part]) {
+    let input = inputs::Store::from_env()
+        .read(day)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let mut group = c.benchmark_group(format!("day{day:02}"));
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(black_box(&input)).unwrap())
+    });
+
+    let parsed = solution.parse(&input).unwrap();
+    if !skip.contains(&Part::One) {
+        group.bench_function("part1", |b| {
+            b.iter(|| solution.part1(black_box(&parsed)).unwrap())
+        });
+    }
+    if !skip.contains(&Part::Two) {
+        group.bench_function("part2", |b| {
+            b.iter(|| solution.part2(black_box(&parsed)).unwrap())
+        });
+    }
+
+    group.finish();
+}
@@ -0,0 +1,189 @@
+//! Cycle detection for simulations run for more steps than can be afforded.
+//!
+//! A simulation is given by its initial state and a function computing the
+//! next state. Its states must eventually repeat, which holds whenever there
+//! are finitely many of them: the functions here would loop forever otherwise.
+//!
+//! [`brent`] and [`floyd`] only keep a couple of states around, [`hashed`]
+//! keeps every state until the first repeat but steps the fewest times, which
+//! is best when a step costs more than hashing a state.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Where the states of a simulation start repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps to the first state of the cycle.
+    pub start: usize,
+    /// The number of steps to go round the cycle, at least 1.
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The lowest number of steps leading to the same state as `steps` steps.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.len
+        }
+    }
+
+    /// The state after `steps` steps from `initial`, running at most `start + len` steps.
+    pub fn nth<S: Clone>(&self, initial: &S, mut step: impl FnMut(&S) -> S, steps: usize) -> S {
+        let mut state = initial.clone();
+        for _ in 0..self.reduce(steps) {
+            state = step(&state);
+        }
+        state
+    }
+}
+
+/// Brent's algorithm, comparing states to the last one saved at a power of two steps.
+pub fn brent<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare);
+        len += 1;
+    }
+
+    // A hare `len` steps ahead meets the tortoise at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, len }
+}
+
+/// Floyd's algorithm, with a hare going twice as fast as the tortoise.
+pub fn floyd<S: Clone + PartialEq>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    // The hare is now a multiple of the cycle length ahead.
+    let mut tortoise = initial.clone();
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        len += 1;
+    }
+    Cycle { start, len }
+}
+
+/// The states of a simulation up to its first repeat, see [`hashed`].
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    /// The states from the initial one to the last one before the cycle goes round.
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> History<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// The state after `steps` steps, without stepping any more.
+    pub fn nth(&self, steps: usize) -> &S {
+        &self.states[self.cycle.reduce(steps)]
+    }
+}
+
+/// Steps until a state is seen again, remembering every state along with when it was seen.
+pub fn hashed<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let len = states.len() - start;
+            return History {
+                states,
+                cycle: Cycle { start, len },
+            };
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, then 3 to 9 over and over.
+    fn step(n: &u32) -> u32 {
+        if *n == 9 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle { start: 3, len: 7 };
+
+    #[test]
+    fn test_reduce() {
+        assert_eq!(2, CYCLE.reduce(2));
+        assert_eq!(3, CYCLE.reduce(10));
+        assert_eq!(8, CYCLE.reduce(1_000_000_002));
+        assert_eq!(8, CYCLE.nth(&0, step, 1_000_000_002));
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(CYCLE, brent(&0, step));
+        assert_eq!(Cycle { start: 0, len: 7 }, brent(&5, step));
+        assert_eq!(Cycle { start: 0, len: 1 }, brent(&0, |n| *n));
+    }
+
+    #[test]
+    fn test_floyd() {
+        assert_eq!(CYCLE, floyd(&0, step));
+        assert_eq!(Cycle { start: 0, len: 7 }, floyd(&5, step));
+        assert_eq!(Cycle { start: 0, len: 1 }, floyd(&0, |n| *n));
+    }
+
+    #[test]
+    fn test_hashed() {
+        let mut steps = 0;
+        let history = hashed(0, |n| {
+            steps += 1;
+            step(n)
+        });
+        assert_eq!(CYCLE, history.cycle());
+        assert_eq!(10, steps);
+        assert_eq!(&6, history.nth(3 + 7 * 1000 + 3));
+        assert_eq!(&1, history.nth(1));
+    }
+}
@@ -0,0 +1,33 @@
+//! Random calibration documents.
+
+use generate::Rng;
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and spelled-out digits, each with at least one digit.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| line(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn line(rng: &mut Rng) -> String {
+    let mut line = String::new();
+    let digit_at = rng.range(0..=3);
+    for i in 0..=3 {
+        match rng.range(0..=2) {
+            _ if i == digit_at => line.push_str(&rng.range(1..=9).to_string()),
+            0 => line.push_str(SPELLED[rng.below(SPELLED.len())]),
+            1 => line.push_str(&rng.range(1..=9).to_string()),
+            _ => line.extend((0..rng.range(1..=4)).map(|_| letter(rng))),
+        }
+    }
+    line
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.range(0..=25) as u8) as char
+}
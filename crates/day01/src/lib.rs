@@ -0,0 +1,83 @@
+//! Day 1: Trebuchet?!
+
+pub mod generator;
+
+use anyhow::{Context, Result};
+use nom::{character::complete::alphanumeric1, combinator::map};
+use parsing::{lines, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        total(input, digits)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        total(input, spelled_digits)
+    }
+}
+
+/// Sum of the calibration values, the digits of each line being found by `digits`.
+fn total(lines: &[String], digits: impl Fn(&str) -> Vec<u32>) -> Result<Answer> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calibration_value(&digits(line))
+                .with_context(|| format!("line {} has no digit: {line}", i + 1))
+        })
+        .sum()
+}
+
+/// The lines of the calibration document, made of letters and digits.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Source::new(input).parse(lines(map(alphanumeric1, str::to_string)))
+}
+
+/// The numeric digits of a line, in order.
+pub fn digits(line: &str) -> Vec<u32> {
+    line.chars()
+        .filter(|ch| ch.is_numeric())
+        .map(|ch| ch.to_digit(10).unwrap())
+        .collect()
+}
+
+/// The digits of a line, including the ones spelled out with letters.
+///
+/// Spelled digits may overlap, `eightwo` yields both 8 and 2.
+pub fn spelled_digits(line: &str) -> Vec<u32> {
+    let nums_as_letters = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut nums = Vec::new();
+    for (i, ch) in line.char_indices() {
+        if ch.is_numeric() {
+            nums.push(ch.to_digit(10).unwrap());
+            continue;
+        }
+
+        for (n, potential) in nums_as_letters.iter().enumerate() {
+            if line[i..].starts_with(potential) {
+                nums.push(n as u32);
+            }
+        }
+    }
+
+    nums
+}
+
+/// The two-digit number made of the first and last digit, if there is any digit.
+pub fn calibration_value(digits: &[u32]) -> Option<u32> {
+    Some(digits.first()? * 10 + digits.last()?)
+}
@@ -0,0 +1,24 @@
+//! Random records of games of cubes.
+
+use generate::Rng;
+
+/// `size` games of one to six picks of up to 20 cubes of each color.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            let picks = (0..rng.range(1..=6)).map(|_| pick(rng)).collect::<Vec<_>>();
+            format!("Game {id}: {}", picks.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pick(rng: &mut Rng) -> String {
+    let mut colors = ["red", "green", "blue"];
+    rng.shuffle(&mut colors);
+    colors[..rng.range(1..=3) as usize]
+        .iter()
+        .map(|color| format!("{} {color}", rng.range(1..=20)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
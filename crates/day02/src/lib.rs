@@ -0,0 +1,117 @@
+//! Day 2: Cube Conundrum
+
+pub mod generator;
+
+use anyhow::Result;
+use nom::{
+    character::complete::char,
+    combinator::map,
+    sequence::{preceded, separated_pair},
+};
+use parsing::{key_value, lines, list, number, one_of, tag, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .filter_map(|game| {
+                if game.picks.iter().all(|p| p.valid_part1()) {
+                    Some(game.id)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().map(|game| game.min_pick().power()).sum())
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub picks: Vec<Pick>,
+}
+
+impl Game {
+    /// The fewest cubes of each color that make every pick of the game possible.
+    pub fn min_pick(&self) -> Pick {
+        let mut min_pick = Pick::default();
+        for pick in self.picks.iter() {
+            if pick.red > min_pick.red {
+                min_pick.red = pick.red;
+            }
+
+            if pick.green > min_pick.green {
+                min_pick.green = pick.green;
+            }
+
+            if pick.blue > min_pick.blue {
+                min_pick.blue = pick.blue;
+            }
+        }
+        min_pick
+    }
+}
+
+/// A handful of cubes revealed from the bag.
+#[derive(Default, Debug)]
+pub struct Pick {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Pick {
+    /// Whether the pick fits in a bag of 12 red, 13 green and 14 blue cubes.
+    pub fn valid_part1(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    pub fn power(&self) -> Answer {
+        Answer::from(self.red) * self.green * self.blue
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+const COLORS: [(&str, Color); 3] = [
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("blue", Color::Blue),
+];
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    let cubes = separated_pair(number::<u32>, char(' '), one_of(&COLORS));
+    let pick = map(list(tag(", "), cubes), |cubes| {
+        let mut res = Pick::default();
+        for (count, color) in cubes {
+            match color {
+                Color::Red => res.red += count,
+                Color::Green => res.green += count,
+                Color::Blue => res.blue += count,
+            }
+        }
+        res
+    });
+    let game = key_value(preceded(tag("Game "), number), list(tag("; "), pick));
+    Source::new(input).parse(lines(map(game, |(id, picks)| Game { id, picks })))
+}
@@ -0,0 +1,31 @@
+//! Random engine schematics.
+
+use generate::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '%', '@', '&', '-'];
+
+/// A `size` by `size` schematic, numbers never touching each other on a line.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size);
+            while row.len() < size {
+                match rng.range(0..=9) {
+                    0..=5 => row.push('.'),
+                    6 | 7 => {
+                        let digits = rng.range(1..=3).min((size - row.len()) as u64);
+                        row.push_str(&rng.range(1..=10u64.pow(digits as u32) - 1).to_string());
+                        if row.len() < size {
+                            row.push('.');
+                        }
+                    }
+                    _ => row.push(*rng.pick(&SYMBOLS)),
+                }
+            }
+            row.truncate(size);
+            row
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,131 @@
+//! Day 3: Gear Ratios
+
+pub mod generator;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::Result;
+use geometry::Pos;
+use parsing::{grid, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part1())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part2())
+    }
+}
+
+/// The engine schematic.
+#[derive(Debug)]
+pub struct Schematic {
+    /// Every symbol that is neither a digit nor a `.`.
+    pub symbols: HashMap<Pos, char>,
+    /// Every number as written, with the position of its first digit, however long it is.
+    pub numbers: Vec<(String, Pos)>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = Source::new(s).parse(grid(Some))?;
+        let mut result = Self {
+            symbols: HashMap::new(),
+            numbers: Vec::new(),
+        };
+        for (x, row) in rows.iter().enumerate() {
+            let mut y = 0;
+            while y < row.len() {
+                let pos = Pos::new(x as i64, y as i64);
+                let digits = row[y..].iter().take_while(|ch| ch.is_ascii_digit()).count();
+                if digits > 0 {
+                    result
+                        .numbers
+                        .push((row[y..y + digits].iter().collect(), pos));
+                    y += digits;
+                    continue;
+                }
+
+                if row[y] != '.' {
+                    result.symbols.insert(pos, row[y]);
+                }
+                y += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Schematic {
+    /// Sum of the numbers adjacent to a symbol, diagonals included.
+    pub fn part1(&self) -> Answer {
+        self.numbers
+            .iter()
+            .filter_map(|(n, pos)| {
+                let check = (0..(n.len() as i64)).any(|i| {
+                    Pos::new(pos.x, pos.y + i)
+                        .neighbors8()
+                        .iter()
+                        .any(|n| self.symbols.contains_key(n))
+                });
+                if check {
+                    Some(number(n))
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    /// Sum of the gear ratios, the products of the two numbers around a `*`.
+    pub fn part2(&self) -> Answer {
+        self.symbols
+            .iter()
+            .filter_map(|(pos, ch)| {
+                if *ch != '*' {
+                    return None;
+                }
+
+                let hit_zone = pos.neighbors8().into_iter().collect::<HashSet<_>>();
+
+                let matches = self
+                    .numbers
+                    .iter()
+                    .filter(|(n, start)| {
+                        (0..(n.len() as i64))
+                            .any(|i| hit_zone.contains(&Pos::new(start.x, start.y + i)))
+                    })
+                    .collect::<Vec<_>>();
+
+                if matches.len() != 2 {
+                    return None;
+                }
+
+                Some(number(&matches[0].0) * number(&matches[1].0))
+            })
+            .sum()
+    }
+}
+
+fn number(digits: &str) -> Answer {
+    // Only ever digits.
+    digits.parse().unwrap()
+}
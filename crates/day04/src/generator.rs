@@ -0,0 +1,29 @@
+//! Random piles of scratchcards.
+
+use generate::Rng;
+
+/// `size` cards of 5 winning numbers and 8 numbers, no card winning copies of cards past
+/// the end of the pile.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (1..=size)
+        .map(|id| {
+            let mut pool = (1..=99).collect::<Vec<u64>>();
+            rng.shuffle(&mut pool);
+            let (winning, others) = pool.split_at(5);
+            let matches = rng.range(0..=(size - id).min(5) as u64) as usize;
+            let mut numbers = [&winning[..matches], &others[..8 - matches]].concat();
+            rng.shuffle(&mut numbers);
+
+            let column = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!("Card {id:>3}: {} | {}", column(winning), column(&numbers))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,124 @@
+//! Day 4: Scratchcards
+
+pub mod generator;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+use math::Overflow;
+use nom::{
+    character::complete::space1,
+    combinator::map,
+    sequence::{pair, preceded, separated_pair},
+};
+use parsing::{key_value, lines, number, numbers, tag, PResult, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Part1 = Answer;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.part1())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        input.part2().context("too many copies of the cards")
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning: HashSet<u32>,
+    pub numbers: HashSet<u32>,
+}
+
+impl Card {
+    /// How many of our numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.numbers).count()
+    }
+
+    /// One point for the first match, doubled for every other one.
+    pub fn score_part1(&self) -> Answer {
+        let count = self.matches() as u32;
+        if count == 0 {
+            return Answer::default();
+        }
+        Answer::from(2).pow(count - 1)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(card)
+    }
+}
+
+/// A card like `Card 1: 41 48 | 83 86 48`, its winning numbers first.
+fn card(input: &str) -> PResult<'_, Card> {
+    let id = preceded(pair(tag("Card"), space1), number);
+    let lists = separated_pair(numbers, tag(" | "), numbers);
+    map(key_value(id, lists), |(id, (winning, numbers))| Card {
+        id,
+        winning: winning.into_iter().collect(),
+        numbers: numbers.into_iter().collect(),
+    })(input)
+}
+
+/// The whole pile of scratchcards.
+#[derive(Debug)]
+pub struct Input {
+    cards: Vec<Card>,
+    counts: HashMap<u32, usize>,
+}
+
+impl Input {
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Total points of the pile.
+    pub fn part1(&self) -> Answer {
+        self.cards.iter().map(|c| c.score_part1()).sum()
+    }
+
+    /// Total number of cards once every won copy is accounted for.
+    pub fn part2(&self) -> Result<usize, Overflow> {
+        let mut counts = self.counts.clone();
+        for card in self.cards.iter() {
+            let wins = card.matches() as u32;
+            let self_count = *counts.get(&card.id).unwrap();
+            for offset in 1..=wins {
+                let ent = counts.entry(math::add(card.id, offset)?).or_default();
+                *ent = math::add(*ent, self_count)?;
+            }
+        }
+
+        math::sum(counts.into_values())
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = Source::new(s).parse(lines(card))?;
+
+        let counts = cards.iter().map(|c| (c.id, 1)).collect();
+        Ok(Input { cards, counts })
+    }
+}
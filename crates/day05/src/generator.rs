@@ -0,0 +1,43 @@
+//! Random almanacs.
+
+use generate::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Four seed ranges and seven maps of `size` lines, the sources of a map never overlapping.
+///
+/// Every number fits in 32 bits, like in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as u64;
+    let limit = u64::from(u32::MAX);
+    let seeds = (0..4)
+        .map(|_| format!("{} {}", rng.range(0..=limit / 2), rng.range(1..=limit / 8)))
+        .collect::<Vec<_>>();
+
+    let mut blocks = vec![format!("seeds: {}", seeds.join(" "))];
+    for name in MAPS {
+        // Each line takes at most its share of the numbers, gap included.
+        let share = limit / size / 2;
+        let mut next = 0;
+        let mut lines = (0..size)
+            .map(|_| {
+                let source = next + rng.range(0..=share);
+                let length = rng.range(1..=share);
+                next = source + length;
+                format!("{} {source} {length}", rng.range(0..=limit - length))
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        blocks.push(format!("{name} map:\n{}", lines.join("\n")));
+    }
+
+    blocks.join("\n\n")
+}
@@ -0,0 +1,381 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+pub mod generator;
+
+use anyhow::{Context, Result};
+use interval::{Interval, PiecewiseMap, RangeSet};
+use math::Overflow;
+use nom::{
+    character::complete::{line_ending, space1},
+    combinator::map,
+    error::context,
+    sequence::{pair, preceded, tuple},
+};
+use parsing::{key_value, lines, number, numbers, tag, PResult, ParseError, Source};
+use solution::Solution;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Game;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<u64> {
+        input
+            .part1()
+            .context("the almanac goes past the largest number")?
+            .context("the almanac has no seeds")
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u64> {
+        if let [seed] = input.seeds.chunks_exact(2).remainder() {
+            return Err(anyhow::anyhow!(
+                "the seed range starting at {seed} has no length"
+            ));
+        }
+        if let Some(ch) = input.seeds.chunks_exact(2).find(|ch| ch[1] == 0) {
+            return Err(anyhow::anyhow!(
+                "the seed range starting at {} has no length",
+                ch[0]
+            ));
+        }
+        input
+            .part2()
+            .context("the almanac goes past the largest number")?
+            .context("the almanac has no seeds")
+    }
+}
+
+/// The almanac: the seeds, followed by each of the maps in order.
+#[derive(Debug)]
+pub struct Game {
+    pub seeds: Vec<u64>,
+    pub seed_to_soil: Vec<Mapping>,
+    pub soil_to_fertilizer: Vec<Mapping>,
+    pub fertilizer_to_water: Vec<Mapping>,
+    pub water_to_light: Vec<Mapping>,
+    pub light_to_temperature: Vec<Mapping>,
+    pub temperature_to_humidity: Vec<Mapping>,
+    pub humidity_to_location: Vec<Mapping>,
+}
+
+impl Game {
+    /// The maps, from seed to location.
+    pub fn maps(&self) -> [&[Mapping]; 7] {
+        [
+            &self.seed_to_soil,
+            &self.soil_to_fertilizer,
+            &self.fertilizer_to_water,
+            &self.water_to_light,
+            &self.light_to_temperature,
+            &self.temperature_to_humidity,
+            &self.humidity_to_location,
+        ]
+    }
+
+    /// The map from seeds to locations, composing every map of the almanac.
+    pub fn seed_to_location(&self) -> Result<PiecewiseMap<u64>, Overflow> {
+        self.maps()
+            .iter()
+            .try_fold(PiecewiseMap::new(), |composed, mapping| {
+                Ok(composed.then(&piecewise(mapping)?))
+            })
+    }
+
+    /// Lowest location of the seeds, if there are any.
+    pub fn part1(&self) -> Result<Option<u64>, Overflow> {
+        let map = self.seed_to_location()?;
+        Ok(self.seeds.iter().map(|&seed| map.apply(seed)).min())
+    }
+
+    /// Lowest location of the seed ranges, mapped as whole intervals, a last seed without
+    /// a length being ignored. `None` when the ranges hold no seed.
+    pub fn part2(&self) -> Result<Option<u64>, Overflow> {
+        let seeds = self
+            .seeds
+            .chunks_exact(2)
+            .map(|ch| Ok(Interval::new(ch[0], math::add(ch[0], ch[1])?)))
+            .collect::<Result<RangeSet<_>, _>>()?;
+        Ok(self.seed_to_location()?.image(&seeds).min())
+    }
+}
+
+/// One map of the almanac as a function, the first line covering a value deciding where it
+/// goes like in [`apply_map`].
+pub fn piecewise(mapping: &[Mapping]) -> Result<PiecewiseMap<u64>, Overflow> {
+    let mut map = PiecewiseMap::new();
+    for m in mapping {
+        // Both ends must fit, values being sent to their offset past `to`.
+        math::add(m.to, m.length)?;
+        map.insert(Interval::new(m.from, math::add(m.from, m.length)?), m.to);
+    }
+    Ok(map)
+}
+
+/// Maps a single value through one map, unmapped values stay the same.
+pub fn apply_map(seed: u64, mapping: &[Mapping]) -> u64 {
+    for m in mapping {
+        if let Some(to) = m.map_seed(seed) {
+            return to;
+        }
+    }
+
+    seed
+}
+
+/// One line of a map: `length` values starting at `from` are sent to `to`.
+#[derive(Debug)]
+pub struct Mapping {
+    pub from: u64,
+    pub to: u64,
+    pub length: u64,
+}
+
+impl Mapping {
+    pub fn map_seed(&self, seed: u64) -> Option<u64> {
+        if seed >= self.from && seed < self.from + self.length {
+            Some(seed - self.from + self.to)
+        } else {
+            None
+        }
+    }
+}
+
+/// The map called `name`: its header, then a mapping per line.
+fn mapping<'a>(name: &'static str) -> impl FnMut(&'a str) -> PResult<'a, Vec<Mapping>> {
+    let line = context(
+        "a destination, a source and a length",
+        tuple((number, preceded(space1, number), preceded(space1, number))),
+    );
+    let line = map(line, |(to, from, length)| Mapping { from, to, length });
+    preceded(
+        pair(tag(name), pair(tag(" map:"), line_ending)),
+        lines(line),
+    )
+}
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    let section = |name| preceded(pair(line_ending, line_ending), mapping(name));
+    let almanac = tuple((
+        key_value(tag("seeds"), numbers),
+        section("seed-to-soil"),
+        section("soil-to-fertilizer"),
+        section("fertilizer-to-water"),
+        section("water-to-light"),
+        section("light-to-temperature"),
+        section("temperature-to-humidity"),
+        section("humidity-to-location"),
+    ));
+    let (
+        (_, seeds),
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    ) = Source::new(input).parse(almanac)?;
+    Ok(Game {
+        seeds,
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use differential::{shrink_number, shrink_vec, Check, Rng};
+
+    use super::*;
+
+    /// An almanac with small numbers: seed ranges as `(start, length)`, and seven maps of
+    /// `(destination, source, length)` lines whose sources do not overlap.
+    #[derive(Debug, Clone)]
+    struct Almanac {
+        seeds: Vec<(u64, u64)>,
+        maps: Vec<Vec<(u64, u64, u64)>>,
+    }
+
+    impl Almanac {
+        fn generate(rng: &mut Rng) -> Self {
+            let seeds = (0..rng.range(1..=3))
+                .map(|_| (rng.range(0..=60), rng.range(1..=10)))
+                .collect();
+            let maps = (0..7)
+                .map(|_| {
+                    let mut next = 0;
+                    (0..rng.range(0..=4))
+                        .map(|_| {
+                            let source = next + rng.range(0..=8);
+                            let length = rng.range(1..=10);
+                            next = source + length;
+                            (rng.range(0..=60), source, length)
+                        })
+                        .collect()
+                })
+                .collect();
+            Self { seeds, maps }
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let shrink_triple = |&(to, from, length): &(u64, u64, u64)| {
+                let mut smaller = Vec::new();
+                smaller.extend(shrink_number(to).into_iter().map(|to| (to, from, length)));
+                smaller.extend(
+                    shrink_number(from)
+                        .into_iter()
+                        .map(|from| (to, from, length)),
+                );
+                smaller.extend(shrink_number(length).into_iter().map(|l| (to, from, l)));
+                smaller
+            };
+
+            let seeds = shrink_vec(&self.seeds, |&(start, length)| {
+                let mut smaller = shrink_number(start)
+                    .into_iter()
+                    .map(|start| (start, length))
+                    .collect::<Vec<_>>();
+                smaller.extend(shrink_number(length).into_iter().map(|l| (start, l)));
+                smaller
+            })
+            .into_iter()
+            .map(|seeds| Self {
+                seeds,
+                maps: self.maps.clone(),
+            });
+            let maps = shrink_vec(&self.maps, |map| shrink_vec(map, shrink_triple))
+                .into_iter()
+                .filter(|maps| maps.len() == 7)
+                .map(|maps| Self {
+                    seeds: self.seeds.clone(),
+                    maps,
+                });
+
+            seeds.chain(maps).filter(Self::is_valid).collect()
+        }
+
+        /// Whether the almanac could be a puzzle input.
+        fn is_valid(&self) -> bool {
+            !self.seeds.is_empty()
+                && self.seeds.iter().all(|&(_, length)| length > 0)
+                && self.maps.iter().all(|map| {
+                    map.iter().all(|&(_, _, length)| length > 0)
+                        && map.iter().enumerate().all(|(i, &(_, a, a_len))| {
+                            map[i + 1..]
+                                .iter()
+                                .all(|&(_, b, b_len)| a + a_len <= b || b + b_len <= a)
+                        })
+                })
+        }
+
+        fn game(&self) -> Game {
+            let mut maps = self.maps.iter().map(|map| {
+                map.iter()
+                    .map(|&(to, from, length)| Mapping { from, to, length })
+                    .collect()
+            });
+            let mut next_map = || maps.next().unwrap();
+            Game {
+                seeds: self.seeds.iter().flat_map(|&(s, l)| [s, l]).collect(),
+                seed_to_soil: next_map(),
+                soil_to_fertilizer: next_map(),
+                fertilizer_to_water: next_map(),
+                water_to_light: next_map(),
+                light_to_temperature: next_map(),
+                temperature_to_humidity: next_map(),
+                humidity_to_location: next_map(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_part2_against_apply_map() {
+        Check::new(500, Almanac::generate, Almanac::shrink).assert_same(
+            |almanac| {
+                let game = almanac.game();
+                almanac
+                    .seeds
+                    .iter()
+                    .flat_map(|&(start, length)| start..start + length)
+                    .map(|seed| game.maps().iter().fold(seed, |s, map| apply_map(s, map)))
+                    .min()
+                    .unwrap()
+            },
+            |almanac| almanac.game().part2().unwrap().unwrap(),
+        );
+    }
+
+    /// An almanac with the given seeds, `first` as its seed-to-soil map and the other
+    /// maps all alike.
+    fn almanac(seeds: &str, first: &str) -> Game {
+        let maps = [
+            "seed-to-soil",
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ];
+        let maps = maps
+            .iter()
+            .enumerate()
+            .map(|(i, name)| format!("{name} map:\n{}", if i == 0 { first } else { "0 0 1" }))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        parse(&format!("seeds: {seeds}\n\n{maps}")).unwrap()
+    }
+
+    #[test]
+    fn test_odd_seeds() {
+        let game = almanac("79 14 55", "52 50 48");
+        assert_eq!(14, Day05.part1(&game).unwrap());
+        let err = Day05.part2(&game).unwrap_err();
+        assert_eq!(
+            "the seed range starting at 55 has no length",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let mut game = almanac("79 14", "52 50 48");
+        game.seeds.clear();
+        let err = Day05.part1(&game).unwrap_err();
+        assert_eq!("the almanac has no seeds", err.to_string());
+        let err = Day05.part2(&game).unwrap_err();
+        assert_eq!("the almanac has no seeds", err.to_string());
+
+        let game = almanac("79 14 5 0", "52 50 48");
+        let err = Day05.part2(&game).unwrap_err();
+        assert_eq!(
+            "the seed range starting at 5 has no length",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let game = almanac("18446744073709551614 1", "0 18446744073709551610 4");
+        assert_eq!(Ok(Some(1)), game.part1());
+        assert_eq!(Ok(Some(18446744073709551614)), game.part2());
+        if cfg!(feature = "checked") {
+            let game = almanac("18446744073709551614 1", "0 18446744073709551614 5");
+            assert_eq!(Err(Overflow), game.part1());
+            assert_eq!(Err(Overflow), game.part2());
+            let game = almanac("18446744073709551614 2", "0 0 1");
+            assert_eq!(Err(Overflow), game.part2());
+        }
+    }
+}
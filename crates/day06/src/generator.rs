@@ -0,0 +1,26 @@
+//! Random sheets of race records.
+
+use generate::Rng;
+
+/// Up to four races, `size` of them, whose records can all be beaten.
+///
+/// Times have two digits, so that the single long race of part 2 stays within reach.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let races = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.range(10..=99);
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..=best - 1))
+        })
+        .collect::<Vec<_>>();
+
+    let row = |name: &str, values: Vec<u64>| {
+        let columns = values.iter().map(|v| format!("{v:>5}")).collect::<String>();
+        format!("{name:<9}{columns}")
+    };
+    [
+        row("Time:", races.iter().map(|r| r.0).collect()),
+        row("Distance:", races.iter().map(|r| r.1).collect()),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,138 @@
+//! Day 6: Wait For It
+
+pub mod generator;
+
+use anyhow::{Context, Result};
+use math::Overflow;
+use nom::{
+    character::complete::line_ending,
+    combinator::{consumed, map},
+    sequence::separated_pair,
+};
+use parsing::{key_value, numbers, tag, ParseError, Source};
+use solution::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Sheet;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        input
+            .races
+            .iter()
+            .try_fold(1usize, |product, race| {
+                product.checked_mul(race.possibilities()?).ok_or(Overflow)
+            })
+            .context("too many ways to beat the records")
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        input
+            .long_race
+            .possibilities()
+            .context("the race is too long")
+    }
+}
+
+/// The sheet of paper with the race records.
+pub struct Sheet {
+    pub races: Vec<Race>,
+    /// The single race obtained when ignoring the spaces between numbers.
+    pub long_race: Race,
+}
+
+pub fn parse(input: &str) -> Result<Sheet, ParseError> {
+    let source = Source::new(input);
+    let line = |key| map(key_value(tag(key), consumed(numbers::<usize>)), |(_, n)| n);
+    let ((times, time_values), (records, record_values)) =
+        source.parse(separated_pair(line("Time"), line_ending, line("Distance")))?;
+    if time_values.len() != record_values.len() {
+        return Err(source.error(
+            records,
+            format!("{} distances, one per race", time_values.len()),
+        ));
+    }
+
+    // The second half reads each line as a single number, ignoring the spacing.
+    let long_number = |line: &str| {
+        line.replace(' ', "")
+            .parse::<usize>()
+            .map_err(|_| source.error(line, "a number"))
+    };
+
+    Ok(Sheet {
+        races: time_values
+            .into_iter()
+            .zip(record_values)
+            .map(|(time, record)| Race::new(time, record))
+            .collect(),
+        long_race: Race::new(long_number(times)?, long_number(records)?),
+    })
+}
+
+pub struct Race {
+    pub time: usize,
+    pub record: usize,
+}
+
+impl Race {
+    pub fn new(time: usize, record: usize) -> Self {
+        Self { time, record }
+    }
+
+    /// Distance travelled when holding the button for `time_press` milliseconds.
+    pub fn sim(&self, time_press: usize) -> usize {
+        let time_left = self.time - time_press;
+        time_left * time_press
+    }
+
+    /// Number of ways to beat the record.
+    ///
+    /// Holding the button for `t` milliseconds beats it when `t * (time - t) > record`, that is
+    /// strictly between the roots of `t² - time * t + record`, which are symmetric around
+    /// `time / 2`. Errors when `time²` does not fit.
+    pub fn possibilities(&self) -> Result<usize, Overflow> {
+        let square = self.time.checked_mul(self.time).ok_or(Overflow)?;
+        let Some(discriminant) = self
+            .record
+            .checked_mul(4)
+            .and_then(|record| square.checked_sub(record))
+        else {
+            return Ok(0);
+        };
+
+        // The square root is rounded down, so start just below the lower root.
+        let mut first = ((self.time - math::isqrt(discriminant)) / 2).saturating_sub(1);
+        while first <= self.time / 2 && self.sim(first) <= self.record {
+            first += 1;
+        }
+        if first > self.time / 2 {
+            return Ok(0);
+        }
+        Ok(self.time - 2 * first + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_possibilities() {
+        for time in 0..40 {
+            for record in 0..=time * time / 4 + 1 {
+                let race = Race::new(time, record);
+                let expected = (0..=time).filter(|&t| race.sim(t) > record).count();
+                assert_eq!(Ok(expected), race.possibilities(), "{time} ms, {record} mm");
+            }
+        }
+        assert_eq!(Err(Overflow), Race::new(usize::MAX, 0).possibilities());
+    }
+}
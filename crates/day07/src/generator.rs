@@ -0,0 +1,24 @@
+//! Random lists of Camel Cards hands.
+
+use generate::Rng;
+
+const CARDS: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+/// `size` hands with their bids, pairs and jokers being common enough to matter.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            // Drawing from a few cards gives more pairs, threes and so on.
+            let mut cards = CARDS;
+            rng.shuffle(&mut cards);
+            let kinds = rng.range(1..=5) as usize;
+            let hand = (0..5)
+                .map(|_| *rng.pick(&cards[..kinds]))
+                .collect::<String>();
+            format!("{hand} {}", rng.range(1..=1000))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
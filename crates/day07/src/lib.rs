@@ -0,0 +1,405 @@
+//! Day 7: Camel Cards
+
+pub mod generator;
+
+use core::panic;
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::Result;
+use nom::{
+    character::complete::char,
+    combinator::map,
+    sequence::{separated_pair, tuple},
+};
+use parsing::{lines, mapped_char, number, PResult, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Game;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Game {
+            plays: parse(input)?,
+            joker_plays: parse_joker(input)?,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        let mut game = input.plays.iter().collect::<Vec<_>>();
+
+        game.sort();
+
+        Ok(game
+            .iter()
+            .enumerate()
+            .map(|(i, play)| Answer::from(i + 1) * play.bid)
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut joker_game = input.joker_plays.iter().collect::<Vec<_>>();
+        joker_game.sort();
+        Ok(joker_game
+            .iter()
+            .enumerate()
+            .map(|(i, play)| Answer::from(i + 1) * play.bid)
+            .sum())
+    }
+}
+
+/// The same list of hands, read with and without jokers.
+pub struct Game {
+    pub plays: Vec<Play>,
+    pub joker_plays: Vec<JokerPlay>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Play>, ParseError> {
+    Source::new(input).parse(lines(play))
+}
+
+/// Same as [`parse`], with `J` read as a joker instead of a jack.
+pub fn parse_joker(input: &str) -> Result<Vec<JokerPlay>, ParseError> {
+    Source::new(input).parse(lines(joker_play))
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Play {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+/// Strength of a hand, weakest first.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Type {
+    High,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl FromStr for Play {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(play)
+    }
+}
+
+/// A play like `32T3K 765`, the hand then the bid.
+fn play(input: &str) -> PResult<'_, Play> {
+    map(separated_pair(hand, char(' '), number), |(hand, bid)| {
+        Play { hand, bid }
+    })(input)
+}
+
+/// Hands order by [`Type`] first, then card by card.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: [Card; 5],
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.kind()
+            .cmp(&other.kind())
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    pub fn kind(&self) -> Type {
+        let mut freq = HashMap::new();
+        for card in self.cards.iter() {
+            let fr = freq.entry(card).or_default();
+            *fr += 1;
+        }
+
+        match freq.len() {
+            1 => Type::FiveOfAKind,
+            2 => match freq.values().next() {
+                Some(1) | Some(4) => Type::FourOfAKind,
+                _ => Type::FullHouse,
+            },
+            3 => {
+                if freq.values().any(|v| *v == 3) {
+                    Type::ThreeOfAKind
+                } else {
+                    Type::TwoPair
+                }
+            }
+            4 => Type::OnePair,
+            5 => Type::High,
+            _ => panic!("Unexpected variety of cards"),
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(hand)
+    }
+}
+
+fn hand(input: &str) -> PResult<'_, Hand> {
+    map(tuple((card, card, card, card, card)), |(a, b, c, d, e)| {
+        Hand {
+            cards: [a, b, c, d, e],
+        }
+    })(input)
+}
+
+impl From<[JokerCard; 5]> for Hand {
+    fn from(value: [JokerCard; 5]) -> Self {
+        let cards = [
+            value[0].into(),
+            value[1].into(),
+            value[2].into(),
+            value[3].into(),
+            value[4].into(),
+        ];
+
+        Self { cards }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl From<JokerCard> for Card {
+    fn from(value: JokerCard) -> Self {
+        match value {
+            JokerCard::Joker => Self::Jack,
+            JokerCard::Two => Self::Two,
+            JokerCard::Three => Self::Three,
+            JokerCard::Four => Self::Four,
+            JokerCard::Five => Self::Five,
+            JokerCard::Six => Self::Six,
+            JokerCard::Seven => Self::Seven,
+            JokerCard::Eight => Self::Eight,
+            JokerCard::Nine => Self::Nine,
+            JokerCard::Ten => Self::Ten,
+            JokerCard::Queen => Self::Queen,
+            JokerCard::King => Self::King,
+            JokerCard::Ace => Self::Ace,
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(card)
+    }
+}
+
+impl Card {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '2' => Some(Self::Two),
+            '3' => Some(Self::Three),
+            '4' => Some(Self::Four),
+            '5' => Some(Self::Five),
+            '6' => Some(Self::Six),
+            '7' => Some(Self::Seven),
+            '8' => Some(Self::Eight),
+            '9' => Some(Self::Nine),
+            'T' => Some(Self::Ten),
+            'J' => Some(Self::Jack),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            'A' => Some(Self::Ace),
+            _ => None,
+        }
+    }
+}
+
+fn card(input: &str) -> PResult<'_, Card> {
+    mapped_char("a card, one of 23456789TJQKA", Card::from_char)(input)
+}
+
+/// A card of the second part, where the joker is the weakest card.
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
+pub enum JokerCard {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Queen,
+    King,
+    Ace,
+}
+
+impl FromStr for JokerCard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(joker_card)
+    }
+}
+
+impl JokerCard {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '2' => Some(Self::Two),
+            '3' => Some(Self::Three),
+            '4' => Some(Self::Four),
+            '5' => Some(Self::Five),
+            '6' => Some(Self::Six),
+            '7' => Some(Self::Seven),
+            '8' => Some(Self::Eight),
+            '9' => Some(Self::Nine),
+            'T' => Some(Self::Ten),
+            'J' => Some(Self::Joker),
+            'Q' => Some(Self::Queen),
+            'K' => Some(Self::King),
+            'A' => Some(Self::Ace),
+            _ => None,
+        }
+    }
+}
+
+fn joker_card(input: &str) -> PResult<'_, JokerCard> {
+    mapped_char("a card, one of 23456789TJQKA", JokerCard::from_char)(input)
+}
+
+/// A hand of the second part, its [`Type`] is the best one the jokers can make.
+#[derive(Debug, PartialEq, Eq)]
+pub struct JokerHand {
+    pub cards: [JokerCard; 5],
+    pub kind: Type,
+}
+
+impl JokerHand {
+    pub fn new(cards: [JokerCard; 5]) -> Self {
+        let kind = JokerHand::compute_kind(&cards);
+        Self { cards, kind }
+    }
+
+    pub fn compute_kind(cards: &[JokerCard; 5]) -> Type {
+        let cards_without_joker = cards
+            .iter()
+            .filter(|c| **c != JokerCard::Joker)
+            .collect::<Vec<_>>();
+
+        let mut freqs_without_jokers: HashMap<JokerCard, usize> = HashMap::new();
+        let jokers = 5 - cards_without_joker.len();
+        for c in cards_without_joker {
+            let ent = freqs_without_jokers.entry(*c).or_default();
+            *ent += 1usize;
+        }
+
+        match jokers {
+            4 | 5 => Type::FiveOfAKind,
+            3 => match freqs_without_jokers.len() {
+                1 => Type::FiveOfAKind, // AAJJJ
+                2 => Type::FourOfAKind, // KAJJJ
+                _ => panic!(),
+            },
+            2 => match freqs_without_jokers.len() {
+                1 => Type::FiveOfAKind,  // AAAJJ
+                2 => Type::FourOfAKind,  // AAKJJ
+                3 => Type::ThreeOfAKind, // AKTJJ
+                _ => panic!(),
+            },
+            1 => match freqs_without_jokers.len() {
+                1 => Type::FiveOfAKind, // AAAAJ
+                2 => {
+                    if *freqs_without_jokers.values().next().unwrap() == 2 {
+                        Type::FullHouse // AAKKJJ
+                    } else {
+                        Type::FourOfAKind // AAAKJ
+                    }
+                }
+                3 => Type::ThreeOfAKind, // AAKTJ
+                4 => Type::OnePair,      //
+                _ => panic!(),
+            },
+            0 => Hand::from(*cards).kind(),
+            _ => panic!(),
+        }
+    }
+}
+
+impl Ord for JokerHand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.kind
+            .cmp(&other.kind)
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for JokerHand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for JokerHand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(joker_hand)
+    }
+}
+
+fn joker_hand(input: &str) -> PResult<'_, JokerHand> {
+    let cards = tuple((joker_card, joker_card, joker_card, joker_card, joker_card));
+    map(cards, |(a, b, c, d, e)| JokerHand::new([a, b, c, d, e]))(input)
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JokerPlay {
+    pub hand: JokerHand,
+    pub bid: u32,
+}
+
+impl FromStr for JokerPlay {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Source::new(s).parse(joker_play)
+    }
+}
+
+fn joker_play(input: &str) -> PResult<'_, JokerPlay> {
+    map(
+        separated_pair(joker_hand, char(' '), number),
+        |(hand, bid)| JokerPlay { hand, bid },
+    )(input)
+}
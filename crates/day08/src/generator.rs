@@ -0,0 +1,49 @@
+//! Random maps of the haunted wasteland.
+
+use generate::Rng;
+
+/// Left and right instructions, then nodes for one to six ghosts.
+///
+/// Like in the puzzle, each ghost walks from its `..A` node into a cycle closed by its `..Z`
+/// node, `AAA` leading to `ZZZ`. The cycles are between `size` and twice `size` steps long.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 1000) as u64;
+    let rules = (0..rng.range(2..=10))
+        .map(|_| *rng.pick(&['L', 'R']))
+        .collect::<String>();
+
+    let mut nodes = Vec::new();
+    let mut count = 0;
+    for ghost in 0..rng.range(1..=6) as usize {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (name(ghost, 'A'), name(ghost, 'Z')),
+        };
+        let mut cycle = (1..rng.range(size..=2 * size))
+            .map(|_| {
+                count += 1;
+                name(count, letter(count / 576))
+            })
+            .collect::<Vec<_>>();
+        cycle.push(end);
+
+        // Both ways lead to the same node, whatever the instructions.
+        nodes.push(format!("{start} = ({}, {})", cycle[0], cycle[0]));
+        for (i, node) in cycle.iter().enumerate() {
+            let next = &cycle[(i + 1) % cycle.len()];
+            nodes.push(format!("{node} = ({next}, {next})"));
+        }
+    }
+    rng.shuffle(&mut nodes);
+
+    format!("{rules}\n\n{}", nodes.join("\n"))
+}
+
+/// A distinct name for each `n`, ending with `last`, made of letters other than `A` and `Z`.
+fn name(n: usize, last: char) -> String {
+    [letter(n / 24), letter(n), last].iter().collect()
+}
+
+fn letter(n: usize) -> char {
+    (b'B' + (n % 24) as u8) as char
+}
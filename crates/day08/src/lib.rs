@@ -0,0 +1,266 @@
+//! Day 8: Haunted Wasteland
+
+pub mod generator;
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Context, Result};
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{alphanumeric1, char, line_ending},
+    combinator::{map, peek},
+    error::context,
+    sequence::{pair, separated_pair, terminated, tuple},
+};
+use parsing::{lines, tag, ParseError, Source};
+use solution::Solution;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        solve_one(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        solve_two(input)
+    }
+}
+
+/// Steps needed to walk from `AAA` to `ZZZ`.
+pub fn solve_one(network: &Network) -> Result<usize, anyhow::Error> {
+    let rules = &network.rules;
+
+    solution::debug!("Rules: {}", rules.len());
+    let mut current = "AAA";
+    let mut steps = 0;
+    let mut rules_prog = rules.chars().cycle();
+    while current != "ZZZ" {
+        current = network.next(current, rules_prog.next().unwrap())?;
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+/// Steps needed for every ghost to stand on a `..Z` node at once.
+///
+/// Relies on each ghost looping back to its `..Z` node with a fixed period, so the answer is the
+/// least common multiple of those periods.
+pub fn solve_two(network: &Network) -> Result<usize, anyhow::Error> {
+    let Network { rules, graph } = network;
+    let points = graph
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .collect::<Vec<_>>();
+
+    let periods = points
+        .iter()
+        .map(|start| {
+            let mut current = start.as_str();
+            let mut steps = 0;
+            let mut rules_prog = rules.chars().cycle();
+            while !current.ends_with('Z') {
+                current = network.next(current, rules_prog.next().unwrap())?;
+                steps += 1;
+            }
+            Ok(steps)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    solution::debug!("periods: {:?}", periods);
+    if periods.is_empty() {
+        // No ghost, so they all stand on a `..Z` node already.
+        return Ok(0);
+    }
+
+    math::lcm_all(&periods).context("the ghosts take too many steps to meet")
+}
+
+/// Same as [`solve_two`], by moving every ghost one step at a time.
+pub fn solve_two_naive(network: &Network) -> Result<usize, anyhow::Error> {
+    let Network { rules, graph } = network;
+    let mut points = graph
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let mut steps = 0;
+    let mut rules_prog = rules.chars().cycle();
+    while !points.iter().all(|p| p.ends_with('Z')) {
+        points = points
+            .into_iter()
+            .map(|current| network.next(current, rules_prog.next().unwrap()))
+            .collect::<Result<_>>()?;
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let is_rule = |ch| matches!(ch, 'L' | 'R');
+    let rules = terminated(
+        context("L or R", take_while1(is_rule)),
+        context("L or R", peek(line_ending)),
+    );
+    let node = tuple((
+        alphanumeric1,
+        tag(" = ("),
+        alphanumeric1,
+        tag(", "),
+        alphanumeric1,
+        char(')'),
+    ));
+    let node = map(node, |(name, _, left, _, right, _)| (name, left, right));
+    let network = separated_pair(rules, pair(line_ending, line_ending), lines(node));
+    let source = Source::new(input);
+    let (rules, nodes) = source.parse(network)?;
+
+    let names = nodes
+        .iter()
+        .map(|&(name, _, _)| name)
+        .collect::<HashSet<_>>();
+    let undefined = nodes
+        .iter()
+        .flat_map(|&(_, left, right)| [left, right])
+        .find(|next| !names.contains(next));
+    if let Some(undefined) = undefined {
+        return Err(source.error(undefined, "a node of the network"));
+    }
+
+    let graph = nodes
+        .into_iter()
+        .map(|(name, left, right)| {
+            let node = Node {
+                left: left.to_string(),
+                right: right.to_string(),
+            };
+            (name.to_string(), node)
+        })
+        .collect();
+    Ok(Network {
+        rules: rules.to_string(),
+        graph,
+    })
+}
+
+/// The left/right instructions and the nodes they are followed on.
+pub struct Network {
+    pub rules: String,
+    pub graph: HashMap<String, Node>,
+}
+
+impl Network {
+    /// The node reached from `current` by following `rule`.
+    pub fn next(&self, current: &str, rule: char) -> Result<&str> {
+        let node = self
+            .graph
+            .get(current)
+            .with_context(|| format!("there is no node {current}"))?;
+        Ok(if rule == 'L' { &node.left } else { &node.right })
+    }
+}
+
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use differential::{shrink_number, shrink_vec, Check, Rng};
+
+    use super::*;
+
+    /// Ghosts shaped like the puzzle's: each walks from its `..A` node into a cycle of
+    /// `period` steps, which its `..Z` node closes.
+    #[derive(Debug, Clone)]
+    struct Ghosts {
+        rules: String,
+        periods: Vec<u64>,
+    }
+
+    impl Ghosts {
+        fn generate(rng: &mut Rng) -> Self {
+            Self {
+                rules: (0..rng.range(1..=4))
+                    .map(|_| *rng.pick(&['L', 'R']))
+                    .collect(),
+                periods: (0..rng.range(1..=4)).map(|_| rng.range(1..=8)).collect(),
+            }
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let mut smaller = shrink_vec(&self.periods, |&p| shrink_number(p))
+                .into_iter()
+                .filter(|periods| !periods.is_empty() && !periods.contains(&0))
+                .map(|periods| Self {
+                    rules: self.rules.clone(),
+                    periods,
+                })
+                .collect::<Vec<_>>();
+            if self.rules.len() > 1 {
+                smaller.push(Self {
+                    rules: self.rules[1..].to_string(),
+                    periods: self.periods.clone(),
+                });
+            }
+            smaller
+        }
+
+        fn network(&self) -> Network {
+            let mut graph = HashMap::new();
+            for (ghost, &period) in self.periods.iter().enumerate() {
+                let cycle = (1..=period)
+                    .map(|step| match step {
+                        _ if step == period => format!("{ghost}Z"),
+                        _ => format!("{ghost}_{step}"),
+                    })
+                    .collect::<Vec<_>>();
+                graph.insert(format!("{ghost}A"), node(&cycle[0]));
+                for (i, name) in cycle.iter().enumerate() {
+                    graph.insert(name.clone(), node(&cycle[(i + 1) % cycle.len()]));
+                }
+            }
+
+            Network {
+                rules: self.rules.clone(),
+                graph,
+            }
+        }
+    }
+
+    fn node(next: &str) -> Node {
+        Node {
+            left: next.to_string(),
+            right: next.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_solve_two_against_naive() {
+        Check::new(200, Ghosts::generate, Ghosts::shrink).assert_same(
+            |ghosts| solve_two_naive(&ghosts.network()).unwrap(),
+            |ghosts| solve_two(&ghosts.network()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_undefined_nodes() {
+        let err = parse("LR\n\nAAA = (BBB, BBB)\n").err().unwrap();
+        assert_eq!((3, 8), (err.line, err.col));
+        assert_eq!("BBB", err.snippet);
+
+        let network = parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        let err = solve_one(&network).unwrap_err();
+        assert_eq!("there is no node AAA", err.to_string());
+    }
+}
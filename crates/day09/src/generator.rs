@@ -0,0 +1,23 @@
+//! Random oasis reports.
+
+use generate::Rng;
+
+/// `size` histories of 21 values, each the values of a polynomial of degree at most 5, so
+/// that they can be extrapolated.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let coefficients = (0..=rng.range(0..=5))
+                .map(|_| rng.range(0..=10) as i64 - 5)
+                .collect::<Vec<_>>();
+            (0..21)
+                .map(|n: i64| {
+                    let value = coefficients.iter().rev().fold(0, |acc, c| acc * n + c);
+                    value.to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,79 @@
+//! Day 9: Mirage Maintenance
+
+pub mod generator;
+
+use anyhow::{Context, Result};
+use math::Overflow;
+use parsing::{lines, numbers, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        input
+            .iter()
+            .map(|l| next_number(l))
+            .sum::<Result<_, _>>()
+            .context("the history grows too fast")
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        input
+            .iter()
+            .map(|l| previous_number(l))
+            .sum::<Result<_, _>>()
+            .context("the history grows too fast")
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Source::new(input).parse(lines(numbers))
+}
+
+/// Extrapolates the value that follows the history.
+pub fn next_number(input: &[i64]) -> Result<i64, Overflow> {
+    let mut past = vec![*input.last().unwrap()];
+    let mut current = input.to_owned();
+    while !current.iter().all(|n| n == current.first().unwrap()) {
+        let new = differences(&current)?;
+
+        // println!("{:?} => {:?}", current, new);
+        past.push(*new.last().unwrap());
+        current = new;
+    }
+
+    math::sum(past)
+}
+
+/// Extrapolates the value that precedes the history.
+pub fn previous_number(input: &[i64]) -> Result<i64, Overflow> {
+    let mut past = vec![*input.first().unwrap()];
+    let mut current = input.to_owned();
+    while !current.iter().all(|n| n == current.first().unwrap()) {
+        let new = differences(&current)?;
+
+        // println!("{:?} => {:?}", current, new);
+        past.push(*new.first().unwrap());
+        current = new;
+    }
+
+    past.reverse();
+
+    past.into_iter().try_fold(0, |prev, i| math::sub(i, prev))
+}
+
+fn differences(nums: &[i64]) -> Result<Vec<i64>, Overflow> {
+    nums.iter()
+        .zip(nums.iter().skip(1))
+        .map(|(&a, &b)| math::sub(b, a))
+        .collect()
+}
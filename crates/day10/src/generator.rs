@@ -0,0 +1,74 @@
+//! Random fields of pipes.
+
+use std::collections::HashSet;
+
+use generate::{Region, Rng};
+use geometry::{Dir, Pos};
+
+const JUNK: [char; 8] = ['|', '-', 'L', 'J', '7', 'F', '.', '.'];
+
+/// A field of about `size` by `size` tiles holding a single loop, the boundary of a random
+/// region, among unconnected pipes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cells = (size.max(3) - 1) / 2;
+    let region = Region::generate(rng, cells, cells);
+
+    // The corners of the region's cells are every other tile, the sides the tiles between.
+    let corners = region.boundary();
+    let mut tiles = Vec::with_capacity(2 * corners.len());
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        tiles.push(corner * 2);
+        tiles.push(corner + next);
+    }
+
+    let side = 2 * cells + 1;
+    let mut field = (0..side)
+        .map(|_| (0..side).map(|_| *rng.pick(&JUNK)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for (i, &tile) in tiles.iter().enumerate() {
+        let prev = tiles[(i + tiles.len() - 1) % tiles.len()];
+        let next = tiles[(i + 1) % tiles.len()];
+        field[tile.x as usize][tile.y as usize] =
+            pipe(direction(tile, prev), direction(tile, next));
+    }
+
+    // Tiles next to the start that are not part of the loop must not connect to it.
+    let start = tiles[rng.below(tiles.len())];
+    let on_loop = tiles.iter().collect::<HashSet<_>>();
+    for n in start.neighbors() {
+        if !on_loop.contains(&n)
+            && (0..side as i64).contains(&n.x)
+            && (0..side as i64).contains(&n.y)
+        {
+            field[n.x as usize][n.y as usize] = '.';
+        }
+    }
+    field[start.x as usize][start.y as usize] = 'S';
+
+    field
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn direction(from: Pos, to: Pos) -> Dir {
+    Dir::all()
+        .into_iter()
+        .find(|dir| from.mv(*dir) == to)
+        .unwrap()
+}
+
+/// The pipe connecting two directions.
+fn pipe(a: Dir, b: Dir) -> char {
+    match (a.min(b), a.max(b)) {
+        (Dir::North, Dir::South) => '|',
+        (Dir::East, Dir::West) => '-',
+        (Dir::North, Dir::East) => 'L',
+        (Dir::North, Dir::West) => 'J',
+        (Dir::South, Dir::West) => '7',
+        (Dir::East, Dir::South) => 'F',
+        _ => unreachable!("a pipe has two different ends"),
+    }
+}
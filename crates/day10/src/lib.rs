@@ -0,0 +1,347 @@
+//! Day 10: Pipe Maze
+
+pub mod generator;
+
+use std::{collections::HashSet, fmt::Display, io::Write, str::FromStr};
+
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use animate::Frame;
+use anyhow::{Context, Ok, Result};
+use geometry::{BoundingBox, Pos};
+use grid::Grid;
+use parsing::{ParseError, Source};
+use render::{Picture, Rgb};
+use search::Flow;
+use solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Labyrinth;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+fn part1(game: &Labyrinth) -> Result<usize> {
+    let mut game = game.clone();
+    let starting = game.starting_position().context("no starting tile")?;
+    let starting_cell = game
+        .starting_pipe()
+        .context("no pipe fits the starting tile")?;
+    game.map[starting] = Cell::Starting(Some(Box::new(starting_cell)));
+
+    let pipes = game.connected()?;
+    let farthest = pipes.iter().map(|x| x.0).max().unwrap();
+    if animate::enabled() {
+        animate_walk(&game, &pipes, farthest);
+    }
+    Ok(farthest)
+}
+
+/// Shows the tiles of the loop being reached from the start, in at most a hundred frames.
+fn animate_walk(game: &Labyrinth, pipes: &[(usize, Pos)], farthest: usize) {
+    let step = (farthest / 100).max(1);
+    let mut reached = 0;
+    loop {
+        animate::frame(|| {
+            let mut frame = Frame::new(game.map.width(), game.map.height());
+            for (pos, cell) in game.map.iter() {
+                frame.set(pos, cell.glyph(), None);
+            }
+            for &(_, pos) in pipes.iter().filter(|(steps, _)| *steps <= reached) {
+                frame.set(pos, game.at(pos).glyph(), Some(Color::Green));
+            }
+            frame.caption(format!("{reached} steps from the start"))
+        });
+        if reached == farthest {
+            break;
+        }
+        reached = (reached + step).min(farthest);
+    }
+}
+
+fn part2(game: &Labyrinth) -> Result<usize> {
+    let (game, edges, inside_nodes) = enclosed(game)?;
+    if solution::verbose() {
+        print_labyrinth(&game, &edges, &inside_nodes)?;
+    }
+    Ok(inside_nodes.len())
+}
+
+/// The labyrinth with the starting tile replaced by its pipe, the tiles of the loop and the
+/// tiles it encloses.
+pub fn enclosed(game: &Labyrinth) -> Result<(Labyrinth, HashSet<Pos>, HashSet<Pos>)> {
+    let mut game = game.clone();
+    let starting = game.starting_position().context("no starting tile")?;
+    let starting_cell = game
+        .starting_pipe()
+        .context("no pipe fits the starting tile")?;
+    game.map[starting] = Cell::Starting(Some(Box::new(starting_cell.clone())));
+
+    let pipes = game.connected()?;
+    game.map[starting] = starting_cell;
+
+    let edges = pipes.iter().map(|(_, pos)| *pos).collect::<HashSet<Pos>>();
+    let bbox = BoundingBox::from_points(edges.iter().copied()).unwrap();
+
+    let mut inside_nodes = HashSet::new();
+    for x in bbox.min.x..=bbox.max.x {
+        let mut inside = false;
+        for y in bbox.min.y..=bbox.max.y {
+            let pos = Pos { x, y };
+            if edges.contains(&pos) {
+                if matches!(game.at(pos), Cell::Vertical | Cell::UpLeft | Cell::UpRight) {
+                    inside = !inside;
+                }
+            } else if inside {
+                inside_nodes.insert(pos);
+            }
+        }
+    }
+
+    Ok((game, edges, inside_nodes))
+}
+
+/// The labyrinth with the loop in green and the enclosed tiles in red.
+pub fn picture(game: &Labyrinth) -> Result<Picture> {
+    let starting = game.starting_position().context("no starting tile")?;
+    let (game, edges, inside) = enclosed(game)?;
+    let mut picture = Picture::new(game.map.width(), game.map.height());
+    for (pos, cell) in game.map.iter() {
+        if edges.contains(&pos) {
+            picture.fill(pos, Rgb::GREEN);
+        } else if inside.contains(&pos) {
+            picture.fill(pos, Rgb::RED);
+        }
+        let glyph = if pos == starting { 'S' } else { cell.glyph() };
+        picture.glyph(pos, glyph);
+    }
+    Ok(picture)
+}
+
+/// Prints the labyrinth to stderr, with the loop in green and the enclosed tiles in red.
+pub fn print_labyrinth(lab: &Labyrinth, edge: &HashSet<Pos>, inside: &HashSet<Pos>) -> Result<()> {
+    let mut stderr = StandardStream::stderr(ColorChoice::Always);
+
+    let mut edge_color = ColorSpec::new();
+    edge_color.set_fg(Some(Color::Green));
+    let mut inside_color = ColorSpec::new();
+    inside_color.set_fg(Some(Color::Red));
+
+    for (pos, cell) in lab.map.iter() {
+        if edge.contains(&pos) {
+            stderr.set_color(&edge_color)?;
+        }
+        if inside.contains(&pos) {
+            stderr.set_color(&inside_color)?;
+        }
+        write!(&mut stderr, "{}", cell)?;
+        stderr.reset()?;
+        if pos.y as usize == lab.map.width() - 1 {
+            writeln!(&mut stderr)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The field of pipes.
+#[derive(Clone)]
+pub struct Labyrinth {
+    pub map: Grid<Cell>,
+}
+
+impl Labyrinth {
+    pub fn at(&self, p: Pos) -> &Cell {
+        &self.map[p]
+    }
+
+    /// Every tile of the loop with its distance from the start, in the order they were reached.
+    ///
+    /// The starting tile must have been given its actual pipe, see [`Cell::Starting`]. Errors
+    /// when a pipe of the loop leads to a tile that does not lead back to it.
+    pub fn connected(&self) -> Result<Vec<(usize, Pos)>> {
+        let starting = self.starting_position().context("no starting tile")?;
+        let mut broken = None;
+        let visited = search::bfs(
+            [starting],
+            |&pos| {
+                let next = self.at(pos).next(pos);
+                if !next.iter().all(|n| self.leads_to(*n, pos)) {
+                    broken.get_or_insert(pos);
+                }
+                next.into_iter().filter(|n| self.map.in_bounds(*n))
+            },
+            |_, _| Flow::Continue,
+        );
+        if let Some(pos) = broken {
+            return Err(anyhow::anyhow!(
+                "the loop is broken at line {}, column {}",
+                pos.x + 1,
+                pos.y + 1
+            ));
+        }
+        Ok(visited.iter().map(|(pos, steps)| (steps, *pos)).collect())
+    }
+
+    /// Whether the tile at `from` is a pipe leading to `to`.
+    pub fn leads_to(&self, from: Pos, to: Pos) -> bool {
+        self.map
+            .get(from)
+            .is_some_and(|cell| cell.next(from).contains(&to))
+    }
+
+    pub fn starting_position(&self) -> Option<Pos> {
+        self.map.find(|cell| matches!(cell, Cell::Starting(_)))
+    }
+
+    /// The pipe hidden under the starting tile: the one whose both ends lead to a pipe
+    /// connected back to it.
+    pub fn starting_pipe(&self) -> Option<Cell> {
+        let starting = self.starting_position()?;
+        Cell::PIPES.into_iter().find(|pipe| {
+            pipe.next(starting)
+                .iter()
+                .all(|pos| self.leads_to(*pos, starting))
+        })
+    }
+}
+
+impl FromStr for Labyrinth {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        let source = Source::new(s);
+        let labyrinth = Self {
+            map: Grid::parse(s, |ch| ch.try_into().ok())?,
+        };
+        match labyrinth.starting_position() {
+            Some(_) => std::prelude::v1::Ok(labyrinth),
+            None => Err(source.error(source.end(), "a starting tile S")),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum Cell {
+    Ground,
+    /// The animal's tile, along with the pipe hidden under it once known.
+    Starting(Option<Box<Cell>>),
+    Vertical,
+    Horizontal,
+    UpRight,
+    UpLeft,
+    DownLeft,
+    DownRight,
+}
+
+impl Cell {
+    /// Every kind of pipe.
+    pub const PIPES: [Cell; 6] = [
+        Cell::Vertical,
+        Cell::Horizontal,
+        Cell::UpRight,
+        Cell::UpLeft,
+        Cell::DownLeft,
+        Cell::DownRight,
+    ];
+
+    /// The two tiles this pipe connects `p` to.
+    pub fn next(&self, p: Pos) -> Vec<Pos> {
+        let [up, right, down, left] = p.neighbors();
+        match self {
+            Cell::Ground => vec![],
+            Cell::Starting(ref x) => {
+                if let Some(x) = x {
+                    x.next(p)
+                } else {
+                    vec![]
+                }
+            }
+            Cell::Vertical => vec![up, down],
+            Cell::Horizontal => vec![left, right],
+            Cell::UpRight => vec![up, right],
+            Cell::UpLeft => vec![up, left],
+            Cell::DownLeft => vec![down, left],
+            Cell::DownRight => vec![down, right],
+        }
+    }
+
+    /// The char the tile is drawn with in the input.
+    pub fn glyph(&self) -> char {
+        match self {
+            Cell::Ground => '.',
+            Cell::Starting(_) => 'S',
+            Cell::Vertical => '|',
+            Cell::Horizontal => '-',
+            Cell::UpRight => 'L',
+            Cell::UpLeft => 'J',
+            Cell::DownLeft => '7',
+            Cell::DownRight => 'F',
+        }
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> std::prelude::v1::Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Starting(None)),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::UpRight),
+            'J' => Ok(Self::UpLeft),
+            '7' => Ok(Self::DownLeft),
+            'F' => Ok(Self::DownRight),
+            x => Err(anyhow::anyhow!("Unknown cell {}", x)),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+        f.write_char(self.glyph())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, sample::select};
+
+    use crate::{part1, Cell, Labyrinth};
+
+    #[test]
+    fn test_invalid_labyrinths() {
+        let err = "-7\n|J\n".parse::<Labyrinth>().err().unwrap();
+        assert_eq!("a starting tile S", err.expected);
+
+        let labyrinth = "S-7\n|.|\nL-|\n".parse::<Labyrinth>().unwrap();
+        let err = part1(&labyrinth).unwrap_err();
+        assert_eq!("the loop is broken at line 3, column 2", err.to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn test_cell_round_trip(ch in prop_oneof![select(".S|-LJ7F".chars().collect::<Vec<_>>()), any::<char>()]) {
+            if let Ok(cell) = Cell::try_from(ch) {
+                prop_assert_eq!(ch.to_string(), cell.to_string());
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Random images of galaxies.
+
+use generate::Rng;
+
+/// A `size` by `size` image with about one galaxy in ten tiles and a few empty rows and
+/// columns.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let empty_rows = (0..size).map(|_| rng.one_in(5)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.one_in(5)).collect::<Vec<_>>();
+    (0..size)
+        .map(|x| {
+            (0..size)
+                .map(|y| {
+                    let galaxy = !empty_rows[x] && !empty_cols[y] && rng.one_in(10);
+                    if galaxy {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
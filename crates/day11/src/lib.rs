@@ -0,0 +1,130 @@
+//! Day 11: Cosmic Expansion
+
+pub mod generator;
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use geometry::Pos;
+use grid::Grid;
+use math::Overflow;
+use solution::{Answer, Solution};
+
+pub struct Day11 {
+    /// How many rows or columns an empty one stands for in the second part.
+    pub expansion: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            expansion: 1_000_000,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = BaseGrid;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Grid::parse(input, |ch| match ch {
+            '#' => Some(Cell::Galaxy),
+            '.' => Some(Cell::Empty),
+            _ => None,
+        })?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        solve(input, 2).context("the universe expands too far")
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        solve(input, self.expansion).context("the universe expands too far")
+    }
+}
+
+fn solve(base_grid: &BaseGrid, expansion_factor: usize) -> Result<Answer, Overflow> {
+    let expanded = ExpandedGrid::from(base_grid, expansion_factor)?;
+    Ok(expanded
+        .pairs()
+        .into_iter()
+        .map(|(a, b)| a.manhattan(b))
+        .sum())
+}
+
+/// The galaxies once the universe has expanded.
+pub struct ExpandedGrid {
+    pub galaxies: HashSet<Pos>,
+}
+
+impl ExpandedGrid {
+    /// Expands `value`, each empty row or column becomes `expansion_factor` of them.
+    pub fn from(value: &BaseGrid, expansion_factor: usize) -> Result<Self, Overflow> {
+        // Scan the rows and cols to check which ones are empty.
+        let empty_rows: HashSet<usize> = (0..value.height())
+            .filter(|row| value.row(*row).all(|c| matches!(c, Cell::Empty)))
+            .collect();
+        let empty_cols: HashSet<usize> = (0..value.width())
+            .filter(|col| value.col(*col).all(|c| matches!(c, Cell::Empty)))
+            .collect();
+
+        let mut galaxies = HashSet::new();
+
+        let mut expanded_row = 0;
+        for row in 0..value.height() {
+            if empty_rows.contains(&row) {
+                expanded_row = math::add(expanded_row, expansion_factor)?;
+            } else {
+                let mut expanded_col = 0;
+                for col in 0..value.width() {
+                    if matches!(value[Pos::new(row as i64, col as i64)], Cell::Galaxy) {
+                        galaxies.insert(Pos::new(
+                            coordinate(expanded_row)?,
+                            coordinate(expanded_col)?,
+                        ));
+                        expanded_col = math::add(expanded_col, 1)?;
+                    } else if empty_cols.contains(&col) {
+                        expanded_col = math::add(expanded_col, expansion_factor)?;
+                    } else {
+                        expanded_col = math::add(expanded_col, 1)?;
+                    }
+                }
+                expanded_row = math::add(expanded_row, 1)?;
+            }
+        }
+
+        Ok(Self { galaxies })
+    }
+}
+
+fn coordinate(n: usize) -> Result<i64, Overflow> {
+    i64::try_from(n).map_err(|_| Overflow)
+}
+
+impl ExpandedGrid {
+    /// Every pair of galaxies, each pair appearing once.
+    pub fn pairs(&self) -> Vec<(Pos, Pos)> {
+        let mut res = Vec::new();
+
+        for gal in self.galaxies.iter() {
+            for other_gal in self.galaxies.iter() {
+                if gal < other_gal {
+                    res.push((*gal, *other_gal));
+                }
+            }
+        }
+
+        res
+    }
+}
+
+/// The image as observed, before expansion.
+pub type BaseGrid = Grid<Cell>;
+
+#[derive(Debug)]
+pub enum Cell {
+    Empty,
+    Galaxy,
+}
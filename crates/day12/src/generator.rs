@@ -0,0 +1,30 @@
+//! Random condition records of springs.
+
+use generate::Rng;
+
+/// `size` rows of up to 12 springs with at least one arrangement: random rows, their groups,
+/// then some springs turned into unknowns.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut row = (0..rng.range(1..=12))
+                .map(|_| *rng.pick(&['.', '#']))
+                .collect::<Vec<_>>();
+            // A record always has a group.
+            let damaged = rng.below(row.len());
+            row[damaged] = '#';
+
+            let groups = row
+                .split(|ch| *ch == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+            let springs = row
+                .iter()
+                .map(|&ch| if rng.one_in(2) { '?' } else { ch })
+                .collect::<String>();
+            format!("{springs} {}", groups.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
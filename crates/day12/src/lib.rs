@@ -0,0 +1,616 @@
+//! Day 12: Hot Springs
+
+pub mod generator;
+
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use math::Overflow;
+use memo::{Memo, Stats};
+use nom::{character::complete::char, combinator::map, multi::many1, sequence::separated_pair};
+use parsing::{lines, list, mapped_char, number, PResult, ParseError, Source};
+use solution::Solution;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        solve(input, 1).context("too many arrangements")
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        solve(input, 5).context("too many arrangements")
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    Source::new(input).parse(lines(line))
+}
+
+/// Counts the arrangements of a line, memoized in `memo`.
+///
+/// The memo is keyed by the offsets of the first cell and group left to place, so it must
+/// only ever be used for the one line.
+pub fn solve_line(
+    line: &Line,
+    memo: &mut Memo<(usize, usize), Result<usize, Overflow>>,
+) -> Result<usize, Overflow> {
+    arrangements_from(line, 0, 0, memo)
+}
+
+/// The arrangements of the cells from `cell` on, with the groups from `group` on.
+fn arrangements_from(
+    line: &Line,
+    cell: usize,
+    group: usize,
+    memo: &mut Memo<(usize, usize), Result<usize, Overflow>>,
+) -> Result<usize, Overflow> {
+    memo.get(&(cell, group), |memo| {
+        let Some(&first) = line.grid.get(cell) else {
+            return Ok(usize::from(group == line.groups.len()));
+        };
+
+        let mut res = 0;
+        if first != Cell::Damaged {
+            res = arrangements_from(line, cell + 1, group, memo)?;
+        }
+        if first != Cell::Operational {
+            if let Some(&size) = line.groups.get(group) {
+                // The group covers the next `size` cells, and the one after must not be damaged.
+                let end = cell + size;
+                let fits = line
+                    .grid
+                    .get(cell..end)
+                    .is_some_and(|run| !run.contains(&Cell::Operational))
+                    && line.grid.get(end) != Some(&Cell::Damaged);
+                if fits {
+                    let next = (end + 1).min(line.grid.len());
+                    res = math::add(res, arrangements_from(line, next, group + 1, memo)?)?;
+                }
+            }
+        }
+        Ok(res)
+    })
+}
+
+/// Counts the arrangements of every line once unfolded `factor` times.
+pub fn solve(lines: &[Line], factor: usize) -> Result<usize, Overflow> {
+    let mut res = 0;
+    let mut stats = Stats::default();
+    for line in lines {
+        let mut memo = Memo::new();
+        let arrangements = solve_line(&line.multiplied(factor), &mut memo)?;
+        stats += memo.stats();
+
+        solution::debug!("{arrangements} arrangements");
+        res = math::add(res, arrangements)?;
+    }
+    solution::debug!("cache: {stats}");
+    Ok(res)
+}
+
+/// A row of springs, with the sizes of the contiguous groups of damaged ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub grid: Vec<Cell>,
+    pub groups: Vec<usize>,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        Source::new(s).parse(line)
+    }
+}
+
+/// A line like `???.### 1,1,3`, the springs then the groups.
+fn line(input: &str) -> PResult<'_, Line> {
+    let cell = mapped_char("one of #.?", |ch| match ch {
+        '#' => Some(Cell::Damaged),
+        '.' => Some(Cell::Operational),
+        '?' => Some(Cell::Unknown),
+        _ => None,
+    });
+    let groups = list(char(','), number);
+    map(
+        separated_pair(many1(cell), char(' '), groups),
+        |(grid, groups)| Line { grid, groups },
+    )(input)
+}
+
+impl Line {
+    /// Drops the known cells up to the first unknown one, along with the groups they complete.
+    pub fn remove_prefix(&self) -> Self {
+        let mut new_grid = Vec::with_capacity(self.grid.len());
+        let mut new_groups = Vec::with_capacity(self.groups.len());
+
+        let mut groups_iter = self.groups.iter();
+        let mut grid_iter = self.grid.iter();
+        let mut cur_group = None;
+        let mut prev = None;
+        for cell in grid_iter.by_ref() {
+            match cell {
+                Cell::Operational => {
+                    prev = Some(Cell::Operational);
+                }
+                Cell::Damaged => {
+                    prev = Some(Cell::Damaged);
+                    if cur_group.is_none() {
+                        cur_group = groups_iter.next().copied();
+                    }
+
+                    if let Some(ref mut grp) = cur_group {
+                        *grp -= 1;
+                        if *grp == 0 {
+                            cur_group = None;
+                        }
+                    }
+                }
+                Cell::Unknown => {
+                    new_grid.push(Cell::Unknown);
+                    break;
+                }
+            };
+        }
+
+        if let Some(mut cur_group) = cur_group {
+            if prev == Some(Cell::Damaged) {
+                cur_group += 1;
+            }
+            new_groups.push(cur_group);
+        } else if prev == Some(Cell::Damaged) {
+            new_groups.push(1);
+        }
+
+        for left in groups_iter {
+            new_groups.push(*left);
+        }
+
+        if prev == Some(Cell::Damaged) {
+            new_grid.insert(0, Cell::Damaged);
+        }
+
+        for left in grid_iter {
+            new_grid.push(*left);
+        }
+
+        Self {
+            grid: new_grid,
+            groups: new_groups,
+        }
+    }
+
+    /// Unfolds the line: `factor` copies of the cells joined by unknowns, and as many groups.
+    pub fn multiplied(&self, factor: usize) -> Self {
+        let mut new_grid = self.grid.clone();
+        for _ in 1..factor {
+            new_grid.push(Cell::Unknown);
+            new_grid.append(&mut self.grid.clone());
+        }
+        Self {
+            grid: new_grid,
+            groups: self.groups.repeat(factor),
+        }
+    }
+
+    /// The runs of cells that are not operational.
+    pub fn subgroups(&self) -> Vec<Vec<Cell>> {
+        self.grid
+            .split(|cell| *cell == Cell::Operational)
+            .filter_map(|spl| {
+                if spl.is_empty() {
+                    None
+                } else {
+                    Some(spl.to_vec())
+                }
+            })
+            .collect()
+    }
+
+    /// Every way of distributing the groups over the [`subgroups`](Self::subgroups).
+    pub fn potentials(&self) -> Vec<Vec<Line>> {
+        let subgroups = self.subgroups();
+
+        arrangements(Vec::new(), &subgroups, &self.groups)
+    }
+
+    /// Whether the cells before the first unknown can still match the groups.
+    pub fn valid_prefix(&self) -> bool {
+        let mut groups = self.groups.iter();
+        let mut current_group = 0;
+
+        for cell in self.grid.iter() {
+            match cell {
+                Cell::Unknown => {
+                    break;
+                }
+                Cell::Operational => {
+                    if current_group == 0 {
+                        continue;
+                    }
+
+                    let Some(expected) = groups.next() else {
+                        return false;
+                    };
+
+                    if *expected != current_group {
+                        return false;
+                    }
+
+                    current_group = 0;
+                }
+                Cell::Damaged => {
+                    current_group += 1;
+                }
+            }
+        }
+        if current_group == 0 {
+            return true;
+        }
+        let Some(expected) = groups.next() else {
+            return false;
+        };
+
+        current_group <= *expected
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Cell {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+pub fn arrangements(prefix: Vec<Line>, submaps: &[Vec<Cell>], groups: &[usize]) -> Vec<Vec<Line>> {
+    if submaps.is_empty() || groups.is_empty() {
+        if !groups.is_empty()
+            || !submaps
+                .iter()
+                .all(|sm| sm.iter().all(|c| *c == Cell::Unknown))
+        {
+            return vec![];
+        } else {
+            return vec![prefix];
+        }
+    }
+
+    let mut res = Vec::new();
+
+    let next_group = &submaps[0];
+    let current_damaged = next_group
+        .iter()
+        .filter(|cell| **cell == Cell::Damaged)
+        .count();
+    let max_len = next_group.len();
+    for taken_groups in 0..=groups.len() {
+        let taken = &groups[..taken_groups];
+        let damaged = taken.iter().sum::<usize>();
+        let space_required = if taken.is_empty() {
+            0usize
+        } else {
+            damaged + taken.len() - 1usize
+        };
+        if current_damaged > damaged || space_required > max_len {
+            continue;
+        }
+
+        // This is a potentially valid prefix, build it and recurse to check if we have to add it
+
+        let new_groups = &groups[taken_groups..];
+        let new_submaps = &submaps[1..];
+        let mut new_prefix = prefix.clone();
+        new_prefix.push(Line {
+            grid: next_group.clone(),
+            groups: taken.to_vec(),
+        });
+        res.append(&mut arrangements(new_prefix, new_submaps, new_groups));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::slice;
+
+    use differential::{shrink_vec, Check, Rng};
+
+    use super::*;
+
+    /// Counts the arrangements of a line by trying every possible replacement of its unknowns.
+    fn solve_line_very_dumb(line: &Line) -> usize {
+        let Some(missing) = line.missing_damaged() else {
+            return 0;
+        };
+        variants(line.count_unknowns(), missing)
+            .filter(|l| line.with_replacements_for_unknown(l).valid())
+            .count()
+    }
+
+    /// Same as [`solve`] on the lines unfolded five times, by trying every replacement.
+    fn solve_part2_very_dumb(lines: &[Line]) -> usize {
+        lines
+            .iter()
+            .map(|line| solve_line_very_dumb(&line.multiplied(5)))
+            .sum()
+    }
+
+    impl Line {
+        /// Whether the line has no unknowns left and matches its groups.
+        fn valid(&self) -> bool {
+            let mut computed_groups = Vec::new();
+            let mut cur_group = 0;
+            for cell in self.grid.iter() {
+                match cell {
+                    Cell::Operational => {
+                        if cur_group > 0 {
+                            computed_groups.push(cur_group);
+                        }
+                        cur_group = 0;
+                    }
+                    Cell::Damaged => {
+                        cur_group += 1;
+                    }
+                    Cell::Unknown => return false,
+                }
+            }
+
+            if cur_group > 0 {
+                computed_groups.push(cur_group);
+            }
+
+            self.groups == computed_groups
+        }
+
+        /// How many unknowns must be damaged, `None` when the line already has more damaged
+        /// springs than its groups ask for.
+        fn missing_damaged(&self) -> Option<usize> {
+            let total = self.groups.iter().sum::<usize>();
+            let damaged = self
+                .grid
+                .iter()
+                .filter(|cell| **cell == Cell::Damaged)
+                .count();
+            total.checked_sub(damaged)
+        }
+
+        fn count_unknowns(&self) -> usize {
+            self.grid
+                .iter()
+                .filter(|cell| **cell == Cell::Unknown)
+                .count()
+        }
+
+        /// Replaces the first unknowns, in order, with `replacements`.
+        fn with_replacements_for_unknown(&self, replacements: &[Cell]) -> Self {
+            let mut replacements = replacements.iter();
+
+            let mut new_grid = Vec::new();
+
+            for cell in self.grid.iter() {
+                match cell {
+                    Cell::Unknown => {
+                        if let Some(new_cell) = replacements.next() {
+                            new_grid.push(*new_cell);
+                        } else {
+                            new_grid.push(Cell::Unknown);
+                        }
+                    }
+                    _ => new_grid.push(*cell),
+                }
+            }
+
+            Self {
+                grid: new_grid,
+                groups: self.groups.clone(),
+            }
+        }
+    }
+
+    struct VariantInterator {
+        curr: usize,
+        end: usize,
+        length: u32,
+        missing: u32,
+    }
+
+    impl Iterator for VariantInterator {
+        type Item = Vec<Cell>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if self.curr >= self.end {
+                    return None;
+                }
+
+                if self.curr.count_ones() == self.missing {
+                    break;
+                }
+
+                self.curr += 1;
+            }
+
+            let res = self.gen(self.curr);
+
+            self.curr += 1;
+
+            Some(res)
+        }
+    }
+
+    /// Every replacement of `length` unknowns holding exactly `missing` damaged springs.
+    ///
+    /// # Panics
+    ///
+    /// When there are more unknowns than bits in a `usize`, far too many to try anyway.
+    fn variants(length: usize, missing: usize) -> impl Iterator<Item = Vec<Cell>> {
+        let length = u32::try_from(length)
+            .ok()
+            .filter(|&length| length < usize::BITS)
+            .expect("too many unknowns to try every replacement");
+        VariantInterator {
+            curr: 0,
+            end: 1 << length,
+            length,
+            missing: missing as u32,
+        }
+    }
+
+    impl VariantInterator {
+        fn gen(&self, num: usize) -> Vec<Cell> {
+            let mut res = Vec::with_capacity(self.length as usize);
+            for i in (0..self.length).rev() {
+                if num & (1 << i) != 0 {
+                    res.push(Cell::Damaged);
+                } else {
+                    res.push(Cell::Operational);
+                }
+            }
+
+            res
+        }
+    }
+
+    /// A line with at least one arrangement: a random row of springs, its groups, then some
+    /// springs turned into unknowns.
+    fn generate_line(rng: &mut Rng, max_len: u64) -> Line {
+        let row = (0..rng.range(1..=max_len))
+            .map(|_| *rng.pick(&[Cell::Operational, Cell::Damaged]))
+            .collect::<Vec<_>>();
+        let groups = row
+            .split(|cell| *cell == Cell::Operational)
+            .map(<[Cell]>::len)
+            .filter(|&len| len > 0)
+            .collect();
+        let grid = row
+            .into_iter()
+            .map(|cell| if rng.one_in(2) { Cell::Unknown } else { cell })
+            .collect();
+        Line { grid, groups }
+    }
+
+    /// Smaller lines, without a cell or a group, or with a smaller group.
+    fn shrink_line(line: &Line) -> Vec<Line> {
+        let grids = shrink_vec(&line.grid, |_| Vec::new())
+            .into_iter()
+            .map(|grid| Line {
+                grid,
+                groups: line.groups.clone(),
+            });
+        let groups = shrink_vec(&line.groups, |&g| (1..g).rev().collect())
+            .into_iter()
+            .map(|groups| Line {
+                grid: line.grid.clone(),
+                groups,
+            });
+        grids.chain(groups).collect()
+    }
+
+    #[test]
+    fn test_solve_line_against_very_dumb() {
+        let check = Check::new(300, |rng| generate_line(rng, 12), shrink_line);
+        check.assert_same(solve_line_very_dumb, |line| {
+            solve_line(line, &mut Memo::new()).unwrap()
+        });
+        check.assert_same(solve_line_very_dumb, |line| {
+            solve(slice::from_ref(line), 1).unwrap()
+        });
+    }
+
+    #[test]
+    fn test_solve_part2_against_very_dumb() {
+        // Unfolded five times, even short lines have many unknowns.
+        Check::new(30, |rng| generate_line(rng, 3), shrink_line).assert_same(
+            |line| solve_part2_very_dumb(slice::from_ref(line)),
+            |line| solve(slice::from_ref(line), 5).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let line: Line = ".# 1".parse().unwrap();
+        assert_eq!(
+            line.potentials(),
+            vec![vec![Line {
+                grid: vec![Cell::Damaged],
+                groups: vec![1]
+            }]]
+        );
+
+        let line: Line = "???.### 1,1,3".parse().unwrap();
+        assert_eq!(
+            line.potentials(),
+            vec![vec![
+                Line {
+                    grid: vec![Cell::Unknown, Cell::Unknown, Cell::Unknown],
+                    groups: vec![1, 1],
+                },
+                Line {
+                    grid: vec![Cell::Damaged, Cell::Damaged, Cell::Damaged],
+                    groups: vec![3],
+                }
+            ]]
+        );
+
+        let line: Line = "???#???#.?#?????.# 5,1,1,1,2,1"
+            .parse::<Line>()
+            .unwrap()
+            .multiplied(5);
+        assert_eq!(line.potentials().len(), 1);
+
+        let line: Line = "???#????.?? 3,1,1".parse::<Line>().unwrap();
+        assert_eq!(line.potentials().len(), 2);
+
+        let line: Line = "????###??.????#.# 5,1,2,1".parse().unwrap();
+        assert_eq!(line.potentials().len(), 2);
+    }
+
+    #[test]
+    fn test_solve_line() {
+        let line: Line = ".# 1".parse().unwrap();
+        assert_eq!(solve_line(&line, &mut Memo::new()), Ok(1));
+        let line: Line = "???.### 1,1,3".parse().unwrap();
+        assert_eq!(solve_line(&line, &mut Memo::new()), Ok(1));
+        let line: Line = "?###???????? 3,2,1".parse().unwrap();
+        let mut memo = Memo::new();
+        assert_eq!(solve_line(&line, &mut memo), Ok(10));
+        assert!(memo.stats().hits > 0);
+    }
+
+    #[test]
+    fn test_too_many_damaged() {
+        let line: Line = "### 1".parse().unwrap();
+        assert_eq!(line.missing_damaged(), None);
+        assert_eq!(solve_line_very_dumb(&line), 0);
+        assert_eq!(solve_line(&line, &mut Memo::new()), Ok(0));
+    }
+
+    #[test]
+    fn test_remove_prefix() {
+        let line: Line = ".# 1".parse().unwrap();
+        assert_eq!(
+            line.remove_prefix(),
+            Line {
+                grid: vec![Cell::Damaged],
+                groups: vec![1]
+            }
+        );
+
+        let line: Line = ".#.???.#?#?#??##??. 1,1,5,4".parse().unwrap();
+        let without_prefix: Line = "???.#?#?#??##??. 1,5,4".parse().unwrap();
+        assert_eq!(line.remove_prefix(), without_prefix);
+
+        let line: Line = "#?? 1,1".parse().unwrap();
+        let without_prefix: Line = "#?? 1,1".parse().unwrap();
+        assert_eq!(line.remove_prefix(), without_prefix);
+    }
+}
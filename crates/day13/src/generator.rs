@@ -0,0 +1,118 @@
+//! Random patterns of ash and rocks.
+
+use generate::Rng;
+
+/// A pattern, `true` being a rock.
+type Pattern = Vec<Vec<bool>>;
+
+/// `size` patterns of 5 to 17 rows and columns.
+///
+/// Each has a single line of reflection, and a single other line that would be one if
+/// exactly one tile was flipped.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let pattern = loop {
+                let pattern = candidate(rng);
+                let mismatches = lines(&pattern)
+                    .map(|line| mismatches(&pattern, line))
+                    .collect::<Vec<_>>();
+                let count = |n| mismatches.iter().filter(|&&m| m == n).count();
+                if count(0) == 1 && count(1) == 1 {
+                    break pattern;
+                }
+            };
+            pattern
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&rock| if rock { '#' } else { '.' })
+                        .collect()
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// A pattern reflected by a horizontal line, with a tile flipped that breaks an otherwise
+/// perfect vertical one, or the other way round.
+fn candidate(rng: &mut Rng) -> Pattern {
+    let (height, width) = (rng.range(5..=17) as usize, rng.range(5..=17) as usize);
+    let mut pattern = (0..height)
+        .map(|_| (0..width).map(|_| rng.one_in(2)).collect::<Vec<_>>())
+        .collect::<Pattern>();
+
+    // The horizontal line leaves some rows out, the flipped tile is on one of them.
+    let row = rng.range(1..=height as u64 - 1) as usize;
+    let col = rng.range(1..=width as u64 - 1) as usize;
+    for r in &mut pattern {
+        for y in 0..col {
+            if let Some(mirrored) = (2 * col).checked_sub(y + 1).filter(|&m| m < width) {
+                r[mirrored] = r[y];
+            }
+        }
+    }
+    for x in 0..row {
+        if let Some(mirrored) = (2 * row).checked_sub(x + 1).filter(|&m| m < height) {
+            pattern[mirrored] = pattern[x].clone();
+        }
+    }
+
+    let reflected = (2 * row).saturating_sub(height)..(2 * row).min(height);
+    let outside = (0..height)
+        .filter(|x| !reflected.contains(x))
+        .collect::<Vec<_>>();
+    if !outside.is_empty() {
+        let x = outside[rng.below(outside.len())];
+        let reflected_cols = (2 * col).saturating_sub(width)..(2 * col).min(width);
+        let y = reflected_cols.start + rng.below(reflected_cols.len());
+        pattern[x][y] = !pattern[x][y];
+    }
+
+    if rng.one_in(2) {
+        transpose(&pattern)
+    } else {
+        pattern
+    }
+}
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len())
+        .map(|y| pattern.iter().map(|row| row[y]).collect())
+        .collect()
+}
+
+/// Every line of reflection: `(true, n)` is between rows `n - 1` and `n`, `(false, n)`
+/// between columns.
+fn lines(pattern: &Pattern) -> impl Iterator<Item = (bool, usize)> {
+    let (height, width) = (pattern.len(), pattern[0].len());
+    (1..height)
+        .map(|n| (true, n))
+        .chain((1..width).map(|n| (false, n)))
+}
+
+/// How many tiles differ from their reflection.
+fn mismatches(pattern: &Pattern, (horizontal, n): (bool, usize)) -> usize {
+    let pattern = if horizontal {
+        pattern.clone()
+    } else {
+        transpose(pattern)
+    };
+    (0..n)
+        .filter_map(|x| {
+            Some((
+                x,
+                (2 * n).checked_sub(x + 1).filter(|&m| m < pattern.len())?,
+            ))
+        })
+        .map(|(x, mirrored)| {
+            pattern[x]
+                .iter()
+                .zip(&pattern[mirrored])
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
@@ -0,0 +1,342 @@
+//! Day 13: Point of Incidence
+
+pub mod generator;
+
+use anyhow::{Context, Result};
+use geometry::Pos;
+use grid::SparseGrid;
+use nom::combinator::map;
+use parsing::{blocks, PResult, ParseError, Source};
+use solution::Solution;
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+/// Parses the patterns, separated by blank lines.
+pub fn parse(input: &str) -> Result<Vec<Map>, ParseError> {
+    Source::new(input).parse(blocks(pattern))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Map>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<i64> {
+        Ok(input
+            .iter()
+            .filter_map(|m| m.find_reflection())
+            .map(|m| m.score())
+            .sum::<i64>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<i64> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let mirror = m
+                    .find_reflections_with_mistake()
+                    .with_context(|| format!("pattern {} has no smudged reflection", i + 1))?;
+                Ok(mirror.score())
+            })
+            .sum()
+    }
+}
+
+/// A pattern of ash and rocks.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    pub rocks: SparseGrid<()>,
+}
+
+impl Map {
+    pub fn width(&self) -> i64 {
+        self.rocks.width() as i64
+    }
+
+    pub fn height(&self) -> i64 {
+        self.rocks.height() as i64
+    }
+
+    /// The rocks before and after the mirror.
+    fn split(&self, mirror: Mirror) -> (HashSet<Pos>, HashSet<Pos>) {
+        self.rocks
+            .iter()
+            .map(|(pos, _)| pos)
+            .partition(|p| mirror.is_before(*p))
+    }
+
+    /// The mirror that would be perfect if exactly one cell was flipped.
+    pub fn find_reflections_with_mistake(&self) -> Option<Mirror> {
+        for i in 1..self.width() {
+            let m = Mirror::Vertical { col: i };
+            let (before, after) = self.split(m);
+
+            let mut mistakes = 0;
+
+            for x in 0..self.height() {
+                for y in 0..i {
+                    let p = Pos { x, y };
+                    let reflected = m.reflected(p);
+
+                    if !self.rocks.in_bounds(reflected) {
+                        continue;
+                    }
+
+                    if before.contains(&p) != after.contains(&reflected) {
+                        mistakes += 1;
+                    }
+                }
+            }
+
+            if mistakes == 1 {
+                return Some(m);
+            }
+        }
+
+        for i in 1..self.height() {
+            let m = Mirror::Horizontal { row: i };
+            let (before, after) = self.split(m);
+
+            let mut mistakes = 0;
+
+            for x in 0..i {
+                for y in 0..self.width() {
+                    let p = Pos { x, y };
+                    let reflected = m.reflected(p);
+
+                    if !self.rocks.in_bounds(reflected) {
+                        continue;
+                    }
+
+                    if before.contains(&p) != after.contains(&reflected) {
+                        mistakes += 1;
+                    }
+                }
+            }
+
+            if mistakes == 1 {
+                return Some(m);
+            }
+        }
+
+        None
+    }
+
+    /// The perfect mirror of the pattern, if any.
+    pub fn find_reflection(&self) -> Option<Mirror> {
+        'mirror: for i in 1..self.width() {
+            let m = Mirror::Vertical { col: i };
+            let (before, mut after) = self.split(m);
+            for item in before {
+                let reflected = m.reflected(item);
+
+                if !self.rocks.in_bounds(reflected) {
+                    continue;
+                }
+
+                if !after.remove(&reflected) {
+                    continue 'mirror;
+                }
+            }
+
+            if after.is_empty() || after.iter().all(|p| p.y >= 2 * i) {
+                return Some(m);
+            }
+        }
+
+        'mirror: for i in 1..self.height() {
+            let m = Mirror::Horizontal { row: i };
+            let (before, mut after) = self.split(m);
+
+            for item in before {
+                let reflected = m.reflected(item);
+
+                if !self.rocks.in_bounds(reflected) {
+                    continue;
+                }
+
+                if !after.remove(&reflected) {
+                    continue 'mirror;
+                }
+            }
+
+            if after.is_empty() || after.iter().all(|p| p.x >= 2 * i) {
+                return Some(m);
+            }
+        }
+
+        None
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.rocks.render('.', |_| '#'))
+    }
+}
+
+/// A mirror placed right after the given number of rows or columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    Horizontal { row: i64 },
+    Vertical { col: i64 },
+}
+
+impl Mirror {
+    /// The column count, or 100 times the row count, before the mirror.
+    pub fn score(&self) -> i64 {
+        match self {
+            Mirror::Horizontal { row } => *row * 100,
+            Mirror::Vertical { col } => *col,
+        }
+    }
+
+    pub fn is_before(&self, pos: Pos) -> bool {
+        match self {
+            Mirror::Horizontal { row } => pos.x < *row,
+            Mirror::Vertical { col } => pos.y < *col,
+        }
+    }
+
+    pub fn reflected(&self, pos: Pos) -> Pos {
+        match self {
+            Mirror::Horizontal { row } => Pos {
+                x: 2 * *row - 1 - pos.x,
+                y: pos.y,
+            },
+            Mirror::Vertical { col } => Pos {
+                x: pos.x,
+                y: 2 * *col - 1 - pos.y,
+            },
+        }
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Source::new(s).parse(pattern)
+    }
+}
+
+fn pattern(input: &str) -> PResult<'_, Map> {
+    let rocks = SparseGrid::parser('.', |ch| (ch == '#').then_some(()));
+    map(rocks, |rocks| Map { rocks })(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    use solution::Solution;
+
+    use crate::{Day13, Map, Mirror, Pos};
+
+    /// The text of a pattern, one line per row.
+    fn pattern() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            vec(vec(select(vec!['.', '#']), width), height).prop_map(|rows| {
+                rows.iter()
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect()
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(text in pattern()) {
+            let map: Map = text.parse().unwrap();
+            prop_assert_eq!(&text, &map.to_string());
+            prop_assert_eq!(map.to_string().parse::<Map>().unwrap(), map);
+        }
+    }
+
+    #[test]
+    fn test_mirror() {
+        let v = Mirror::Vertical { col: 5 };
+        assert_eq!(Pos { x: 0, y: 8 }, v.reflected(Pos { x: 0, y: 1 }));
+        assert_eq!(Pos { x: 0, y: 1 }, v.reflected(Pos { x: 0, y: 8 }));
+        assert_eq!(Pos { x: 0, y: 7 }, v.reflected(Pos { x: 0, y: 2 }));
+        let h = Mirror::Horizontal { row: 4 };
+        assert_eq!(Pos { x: 1, y: 0 }, h.reflected(Pos { x: 6, y: 0 }));
+        assert_eq!(Pos { x: 6, y: 0 }, h.reflected(Pos { x: 1, y: 0 }));
+        assert_eq!(Pos { x: 4, y: 0 }, h.reflected(Pos { x: 3, y: 0 }));
+        assert_eq!(Pos { x: 3, y: 0 }, h.reflected(Pos { x: 4, y: 0 }));
+    }
+
+    #[test]
+    fn test_find_reflection() {
+        let m: Map = r#".####..#.#.#.##..
+........#..##....
+..##..#.....#..##
+......##.##.#####
+######.#.####....
+..##....#..##.#..
+.#..#..#####.#...
+..##...#..#...#.#
+#######.#....####"#
+            .parse()
+            .unwrap();
+
+        assert_eq!(Some(Mirror::Vertical { col: 3 }), m.find_reflection());
+    }
+
+    #[test]
+    fn test_find_reflection_with_smudge() {
+        let m: Map = r#"###.#...#.#.##.#.
+#.#...####...#..#
+#.#...####...#..#
+###.#...#.#.##.#.
+..####..#####....
+.....###...#..###
+.....###...#..###
+..####..#####....
+###.#...#.#.##.##"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            Some(Mirror::Horizontal { row: 6 }),
+            m.find_reflections_with_mistake()
+        );
+
+        let m: Map = r#"......#
+......#
+..#..#.
+####.#.
+..#.##.
+##.##..
+..#.###
+...###.
+##.###.
+...###.
+..#...#
+##...#.
+##...#.
+####.##
+##.#.##
+###..#.
+.#.##.#"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            Some(Mirror::Vertical { col: 1 }),
+            m.find_reflections_with_mistake()
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(crate::parse("").is_err());
+        let patterns = crate::parse("#.\n..\n\n#.\n.#\n").unwrap();
+        let err = Day13.part2(&patterns).unwrap_err();
+        assert_eq!("pattern 2 has no smudged reflection", err.to_string());
+    }
+}
@@ -0,0 +1,20 @@
+//! Random platforms of rocks.
+
+use generate::Rng;
+
+/// A `size` by `size` platform, a fifth of it rounded rocks and a tenth cube-shaped rocks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.below(10) {
+                    0 | 1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
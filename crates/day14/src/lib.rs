@@ -0,0 +1,257 @@
+//! Day 14: Parabolic Reflector Dish
+
+pub mod generator;
+
+use std::{fmt::Display, str::FromStr};
+
+use animate::{Color, Frame};
+use anyhow::Result;
+use geometry::{Dir, Pos};
+use grid::Grid;
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct Day14 {
+    /// Number of spin cycles to run in the second part.
+    pub cycles: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        let mut map = input.clone();
+        animate::frame(|| map.frame().caption("before tilting"));
+        map.tilt_north();
+        animate::frame(|| map.frame().caption("tilted north"));
+        Ok(map.score())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        Ok(spin(input.clone(), self.cycles))
+    }
+}
+
+/// The tilts making up one spin cycle.
+const SPIN_CYCLE: [Dir; 4] = [Dir::North, Dir::West, Dir::South, Dir::East];
+
+/// Load on the north beams after `cycles` spin cycles, skipping ahead once the
+/// platform starts repeating itself.
+pub fn spin(map: Map, cycles: usize) -> usize {
+    let mut spun = 0;
+    let history = cycle::hashed(map, |map| {
+        let mut map = map.clone();
+        spun += 1;
+        for dir in SPIN_CYCLE {
+            map.apply_direction(dir);
+            animate::frame(|| map.frame().caption(format!("cycle {spun}, tilted {dir:?}")));
+        }
+        map
+    });
+    let cycle = history.cycle();
+    solution::debug!(
+        "Cycle of {} spin cycles found after {} spin cycles",
+        cycle.len,
+        cycle.start
+    );
+
+    let map = history.nth(cycles);
+    animate::frame(|| {
+        map.frame()
+            .caption(format!("cycle {cycles}, skipped ahead"))
+    });
+    map.score()
+}
+
+/// The platform.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Map {
+    pub map: Grid<Cell>,
+}
+
+impl Map {
+    /// Tilts the platform so that every round rock rolls towards `dir`.
+    pub fn apply_direction(&mut self, dir: Dir) {
+        match dir {
+            Dir::North => self.tilt_north(),
+            Dir::West => self.tilt_west(),
+            Dir::South => self.tilt_south(),
+            Dir::East => self.tilt_east(),
+        }
+    }
+
+    pub fn tilt_north(&mut self) {
+        for y in 0..self.map.width() as i64 {
+            // Where the next round rock stops.
+            let mut free = 0;
+            for x in 0..self.map.height() as i64 {
+                match self.map[Pos { x, y }] {
+                    Cell::SquareRock => free = x + 1,
+                    Cell::RoundRock => {
+                        self.map[Pos { x, y }] = Cell::Ground;
+                        self.map[Pos { x: free, y }] = Cell::RoundRock;
+                        free += 1;
+                    }
+                    Cell::Ground => {}
+                }
+            }
+        }
+    }
+
+    /// Tilts north once the platform is turned so that `dir` faces north.
+    fn tilt_rotated(&mut self, dir: Dir) {
+        match dir {
+            Dir::North => self.tilt_north(),
+            Dir::West => {
+                self.map = self.map.rotate_cw();
+                self.tilt_north();
+                self.map = self.map.rotate_ccw();
+            }
+            Dir::South => {
+                self.map = self.map.rotate_cw().rotate_cw();
+                self.tilt_north();
+                self.map = self.map.rotate_ccw().rotate_ccw();
+            }
+            Dir::East => {
+                self.map = self.map.rotate_ccw();
+                self.tilt_north();
+                self.map = self.map.rotate_cw();
+            }
+        }
+    }
+
+    pub fn tilt_south(&mut self) {
+        self.tilt_rotated(Dir::South)
+    }
+
+    pub fn tilt_east(&mut self) {
+        self.tilt_rotated(Dir::East)
+    }
+
+    pub fn tilt_west(&mut self) {
+        self.tilt_rotated(Dir::West)
+    }
+
+    /// The platform with the round rocks in yellow, for animations.
+    pub fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.map.width(), self.map.height());
+        for (pos, cell) in self.map.iter() {
+            match cell {
+                Cell::Ground => frame.set(pos, '.', None),
+                Cell::RoundRock => frame.set(pos, 'O', Some(Color::Yellow)),
+                Cell::SquareRock => frame.set(pos, '#', None),
+            }
+        }
+        frame
+    }
+
+    /// Total load on the north support beams.
+    pub fn score(&self) -> usize {
+        let max_value_per_row = self.map.height();
+
+        let mut score = 0;
+        for (x, row) in self.map.rows().enumerate() {
+            score +=
+                row.iter().filter(|c| **c == Cell::RoundRock).count() * (max_value_per_row - x);
+        }
+
+        score
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        Ok(Map {
+            map: Grid::parse(s, |ch| match ch {
+                '#' => Some(Cell::SquareRock),
+                'O' => Some(Cell::RoundRock),
+                '.' => Some(Cell::Ground),
+                _ => None,
+            })?,
+        })
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.map.render(|cell| match cell {
+            Cell::Ground => '.',
+            Cell::RoundRock => 'O',
+            Cell::SquareRock => '#',
+        }))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Cell {
+    Ground,
+    RoundRock,
+    SquareRock,
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*, sample::select};
+
+    use crate::{spin, Map, SPIN_CYCLE};
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    /// The text of a platform, one line per row.
+    fn platform() -> impl Strategy<Value = String> {
+        (1..12usize, 1..12usize).prop_flat_map(|(width, height)| {
+            vec(vec(select(vec!['.', 'O', '#']), width), height).prop_map(|rows| {
+                rows.iter()
+                    .map(|row| row.iter().collect::<String>() + "\n")
+                    .collect()
+            })
+        })
+    }
+
+    #[test]
+    fn test_spin() {
+        let map: Map = EXAMPLE.parse().unwrap();
+        let mut spun = map.clone();
+        for cycles in 0..30 {
+            assert_eq!(spun.score(), spin(map.clone(), cycles), "{cycles} cycles");
+            for dir in SPIN_CYCLE {
+                spun.apply_direction(dir);
+            }
+        }
+        assert_eq!(64, spin(map, 1_000_000_000));
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(text in platform()) {
+            let map: Map = text.parse().unwrap();
+            prop_assert_eq!(&text, &map.to_string());
+            prop_assert_eq!(map.to_string().parse::<Map>().unwrap(), map);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Random initialization sequences.
+
+use generate::Rng;
+
+const LABELS: [&str; 8] = ["rn", "cm", "qp", "pc", "ot", "ab", "xyz", "hgjk"];
+
+/// `size` steps on a handful of labels, so that lenses get replaced and removed.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let label = *rng.pick(&LABELS);
+            if rng.one_in(3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,160 @@
+//! Day 15: Lens Library
+
+pub mod generator;
+
+use std::str::FromStr;
+
+use anyhow::Result;
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, char},
+    combinator::{consumed, map},
+    error::context,
+    sequence::{separated_pair, terminated},
+};
+use parsing::{list, number, PResult, ParseError, Source};
+use solution::{Answer, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sequence;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let sequence = list(char(','), consumed(instruction));
+        let (steps, instructions) = Source::new(input)
+            .parse(sequence)?
+            .into_iter()
+            .map(|(step, instruction)| (step.to_string(), instruction))
+            .unzip();
+
+        Ok(Sequence {
+            steps,
+            instructions,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.steps.iter().map(|step| hash(step)).sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(focusing_power(&input.instructions))
+    }
+}
+
+/// The initialization sequence, both as raw steps and as instructions.
+pub struct Sequence {
+    pub steps: Vec<String>,
+    pub instructions: Vec<Instruction>,
+}
+
+/// Runs the instructions on the 256 boxes and sums the focusing power of every lens.
+pub fn focusing_power(instructions: &[Instruction]) -> Answer {
+    let mut boxes = Vec::with_capacity(256);
+    for _ in 0..256 {
+        boxes.push(Box::default());
+    }
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Remove(label) => {
+                let hash = hash(label);
+                boxes
+                    .get_mut(hash as usize)
+                    .unwrap()
+                    .lenses
+                    .retain(|l| l.label != *label);
+            }
+            Instruction::Assign(label, length) => {
+                let hash = hash(label);
+                let b = boxes.get_mut(hash as usize).unwrap();
+
+                let mut found = false;
+                for lens in b.lenses.iter_mut() {
+                    if lens.label == *label {
+                        lens.length = *length;
+                        found = true;
+                        break;
+                    }
+                }
+
+                if !found {
+                    b.lenses.push(Lens {
+                        length: *length,
+                        label: label.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut power = Answer::default();
+
+    for (i, b) in boxes.iter().enumerate() {
+        for (il, l) in b.lenses.iter().enumerate() {
+            power += Answer::from(i + 1) * (il + 1) * l.length;
+        }
+    }
+
+    power
+}
+
+/// The Holiday ASCII String Helper algorithm.
+pub fn hash(input: &str) -> u64 {
+    input.chars().fold(0, |mut acc, ch| {
+        let num: u32 = ch.into();
+        acc += num as u64;
+        acc *= 17;
+        acc %= 256;
+
+        acc
+    })
+}
+#[derive(Default)]
+struct Box {
+    lenses: Vec<Lens>,
+}
+
+struct Lens {
+    length: usize,
+    label: String,
+}
+
+pub enum Instruction {
+    Remove(String),
+    Assign(String, usize),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        Source::new(s).parse(instruction)
+    }
+}
+
+/// A step like `rn-` or `cm=2`.
+fn instruction(input: &str) -> PResult<'_, Instruction> {
+    alt((
+        map(terminated(alpha1, char('-')), |label: &str| {
+            Instruction::Remove(label.to_string())
+        }),
+        map(
+            separated_pair(alpha1, char('='), context("a focal length", number)),
+            |(label, length): (&str, usize)| Instruction::Assign(label.to_string(), length),
+        ),
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hash;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(52, hash("HASH"));
+    }
+}
@@ -0,0 +1,24 @@
+//! Random contraptions of mirrors and splitters.
+
+use generate::Rng;
+
+const TILES: [char; 4] = ['|', '-', '/', '\\'];
+
+/// A `size` by `size` contraption, about one tile in six a mirror or a splitter.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.one_in(6) {
+                        *rng.pick(&TILES)
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,281 @@
+//! Day 16: The Floor Will Be Lava
+
+pub mod generator;
+
+use std::{collections::HashSet, str::FromStr};
+
+use animate::{Color, Frame};
+use anyhow::Result;
+use geometry::{Dir, Pos};
+use grid::SparseGrid;
+use parsing::ParseError;
+use render::{Picture, Rgb};
+use search::{Flow, Visited};
+use solution::{Part, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        Ok(energize(input, Pos { x: 0, y: -1 }, Dir::East))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<usize> {
+        Ok(best_energize(input))
+    }
+}
+
+/// The most tiles energized by a beam entering from any edge of the grid.
+pub fn best_energize(map: &Map) -> usize {
+    // Only the best beam is animated, once found.
+    let best = animate::without(|| {
+        entries(map)
+            .into_iter()
+            .max_by_key(|&(start, dir)| energize(map, start, dir))
+    });
+    best.map(|(start, dir)| energize(map, start, dir))
+        .unwrap_or_default()
+}
+
+/// Every beam entering from an edge of the grid, as where it starts, just outside the grid,
+/// and where it heads.
+pub fn entries(map: &Map) -> Vec<(Pos, Dir)> {
+    let (width, height) = (map.items.width() as i64, map.items.height() as i64);
+    let mut entries = Vec::new();
+    for y in 0..width {
+        entries.push((Pos { x: -1, y }, Dir::South));
+        entries.push((Pos { x: height, y }, Dir::North));
+    }
+    for x in 0..height {
+        entries.push((Pos { x, y: -1 }, Dir::East));
+        entries.push((Pos { x, y: width }, Dir::West));
+    }
+    entries
+}
+
+/// Number of tiles energized by a beam entering at `start`, which lies just
+/// outside the grid, heading towards `dir`.
+pub fn energize(map: &Map, start: Pos, dir: Dir) -> usize {
+    energized(map, start, dir).len()
+}
+
+/// The tiles energized by a beam entering at `start` heading towards `dir`.
+pub fn energized(map: &Map, start: Pos, dir: Dir) -> HashSet<Pos> {
+    // The beams move one tile at a time, all together: each frame shows the rays that many
+    // moves away from the start.
+    let mut heads = Vec::new();
+    let mut moves = 0;
+    let visited = search::bfs(
+        [Ray { pos: start, dir }],
+        |head| map.next_rays(head),
+        |head, visited| {
+            if animate::enabled() {
+                let cost = visited.cost(head).unwrap();
+                if cost > moves {
+                    animate::frame(|| map.frame(visited, &heads));
+                    heads.clear();
+                    moves = cost;
+                }
+                heads.push(*head);
+            }
+            Flow::Continue
+        },
+    );
+    animate::frame(|| map.frame(&visited, &heads));
+
+    let mut energized: HashSet<Pos> = visited.iter().map(|(ray, _)| ray.pos).collect();
+    energized.remove(&start);
+    energized
+}
+
+/// The contraption with the tiles energized by the beam of the part in yellow, the part 2
+/// beam being the one energizing the most tiles.
+pub fn picture(map: &Map, part: Part) -> Picture {
+    let (start, dir) = match part {
+        Part::One => (Pos { x: 0, y: -1 }, Dir::East),
+        Part::Two => entries(map)
+            .into_iter()
+            .max_by_key(|&(start, dir)| energize(map, start, dir))
+            .unwrap_or((Pos { x: 0, y: -1 }, Dir::East)),
+    };
+
+    let mut picture = Picture::new(map.items.width(), map.items.height());
+    for pos in energized(map, start, dir) {
+        picture.fill(pos, Rgb::YELLOW);
+    }
+    for (pos, cell) in map.items.iter() {
+        picture.glyph(pos, cell.glyph());
+    }
+    picture.path([start, start.mv(dir)], Rgb::RED);
+    picture
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Ray {
+    pub pos: Pos,
+    pub dir: Dir,
+}
+
+pub enum Cell {
+    HorizontalSplitter, // -
+    VerticalSplitter,   // |
+    LeftLeaning,        // \
+    RightLeaning,       // /
+}
+
+impl Cell {
+    pub fn glyph(&self) -> char {
+        match self {
+            Cell::HorizontalSplitter => '-',
+            Cell::VerticalSplitter => '|',
+            Cell::LeftLeaning => '\\',
+            Cell::RightLeaning => '/',
+        }
+    }
+}
+
+/// The contraption, only mirrors and splitters are stored.
+pub struct Map {
+    pub items: SparseGrid<Cell>,
+}
+
+impl Map {
+    /// The contraption with the energized tiles in yellow and the beams in red.
+    fn frame(&self, visited: &Visited<Ray, usize>, heads: &[Ray]) -> Frame {
+        let energized: HashSet<Pos> = visited.iter().map(|(ray, _)| ray.pos).collect();
+        let (width, height) = (self.items.width(), self.items.height());
+        let mut frame = Frame::new(width, height);
+        for x in 0..height as i64 {
+            for y in 0..width as i64 {
+                frame.set(Pos { x, y }, '.', None);
+            }
+        }
+        for &pos in &energized {
+            frame.set(pos, '#', Some(Color::Yellow));
+        }
+        for (pos, cell) in self.items.iter() {
+            let color = energized.contains(&pos).then_some(Color::Yellow);
+            frame.set(pos, cell.glyph(), color);
+        }
+        for head in heads {
+            frame.set(head.pos, '*', Some(Color::Red));
+        }
+        frame.caption(format!("{} tiles energized", energized.len()))
+    }
+
+    /// Where the beam of `ray` goes after its next move, without allocating when it only moves
+    /// through empty space.
+    fn next_rays(&self, ray: &Ray) -> impl Iterator<Item = Ray> {
+        let n = ray.pos.mv(ray.dir);
+        let (moved, turned) = if !self.items.in_bounds(n) {
+            (None, Vec::new())
+        } else if let Some(item) = self.items.get(n) {
+            (None, self.apply_item(item, n, ray.dir))
+        } else {
+            (
+                Some(Ray {
+                    pos: n,
+                    dir: ray.dir,
+                }),
+                Vec::new(),
+            )
+        };
+        moved.into_iter().chain(turned)
+    }
+
+    /// The rays leaving `pos` when a beam going `going` hits `item` there.
+    pub fn apply_item(&self, item: &Cell, pos: Pos, going: Dir) -> Vec<Ray> {
+        match item {
+            Cell::HorizontalSplitter => match going {
+                Dir::North | Dir::South => {
+                    vec![
+                        Ray {
+                            pos,
+                            dir: Dir::West,
+                        },
+                        Ray {
+                            pos,
+                            dir: Dir::East,
+                        },
+                    ]
+                }
+                _ => vec![Ray { pos, dir: going }],
+            },
+            Cell::VerticalSplitter => match going {
+                Dir::North | Dir::South => vec![Ray { pos, dir: going }],
+                Dir::West | Dir::East => vec![
+                    Ray {
+                        pos,
+                        dir: Dir::North,
+                    },
+                    Ray {
+                        pos,
+                        dir: Dir::South,
+                    },
+                ],
+            },
+            Cell::LeftLeaning => match going {
+                // \
+                Dir::North => vec![Ray {
+                    pos,
+                    dir: Dir::West,
+                }],
+                Dir::South => vec![Ray {
+                    pos,
+                    dir: Dir::East,
+                }],
+                Dir::East => vec![Ray {
+                    pos,
+                    dir: Dir::South,
+                }],
+                Dir::West => vec![Ray {
+                    pos,
+                    dir: Dir::North,
+                }],
+            },
+            Cell::RightLeaning => match going {
+                // /
+                Dir::North => vec![Ray {
+                    pos,
+                    dir: Dir::East,
+                }],
+                Dir::South => vec![Ray {
+                    pos,
+                    dir: Dir::West,
+                }],
+                Dir::East => vec![Ray {
+                    pos,
+                    dir: Dir::North,
+                }],
+                Dir::West => vec![Ray {
+                    pos,
+                    dir: Dir::South,
+                }],
+            },
+        }
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        Ok(Self {
+            items: SparseGrid::parse(s, '.', |ch| match ch {
+                '|' => Some(Cell::VerticalSplitter),
+                '-' => Some(Cell::HorizontalSplitter),
+                '\\' => Some(Cell::LeftLeaning),
+                '/' => Some(Cell::RightLeaning),
+                _ => None,
+            })?,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+//! Random maps of heat loss.
+
+use generate::Rng;
+
+/// A `size` by `size` map, at least 5 by 5 so that the ultra crucible can reach the factory.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
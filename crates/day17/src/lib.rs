@@ -0,0 +1,223 @@
+//! Day 17: Clumsy Crucible
+
+pub mod generator;
+
+use std::str::FromStr;
+
+use animate::{Color, Frame};
+use anyhow::{Context, Result};
+use geometry::{Dir, Pos};
+use grid::Grid;
+use parsing::ParseError;
+use render::{Picture, Rgb};
+use search::{Flow, Visited};
+use solution::{Part, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<u32> {
+        crucible(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<u32> {
+        ultra_crucible(input)
+    }
+}
+
+/// Least heat loss from the top-left to the bottom-right block, moving at most
+/// three blocks in a straight line.
+pub fn crucible(map: &Map) -> Result<u32> {
+    Ok(crucible_plan(map)?.cost)
+}
+
+/// The path of least heat loss of [`crucible`].
+pub fn crucible_plan(map: &Map) -> Result<Plan> {
+    solution::debug!("Map is {}x{}", map.width(), map.height());
+    let to = Pos {
+        x: map.height() - 1,
+        y: map.width() - 1,
+    };
+    find_plan(map, Pos { x: 0, y: 0 }, to, |_previous, next| {
+        next.dir_count <= 3
+    })
+}
+
+/// Least heat loss with an ultra crucible, which moves between four and ten
+/// blocks before turning.
+pub fn ultra_crucible(map: &Map) -> Result<u32> {
+    Ok(ultra_crucible_plan(map)?.cost)
+}
+
+/// The path of least heat loss of [`ultra_crucible`].
+pub fn ultra_crucible_plan(map: &Map) -> Result<Plan> {
+    let to = Pos {
+        x: map.height() - 1,
+        y: map.width() - 1,
+    };
+    find_plan(map, Pos { x: 0, y: 0 }, to, |previous, next| {
+        if previous.dir != next.dir {
+            previous.dir_count >= 4
+        } else {
+            next.dir_count <= 10
+        }
+    })
+}
+
+/// The map shaded by heat loss, with the path of least heat loss of the part.
+pub fn picture(map: &Map, part: Part) -> Result<Picture> {
+    let plan = match part {
+        Part::One => crucible_plan(map)?,
+        Part::Two => ultra_crucible_plan(map)?,
+    };
+
+    let mut picture = Picture::new(map.grid.width(), map.grid.height());
+    for (pos, cost) in map.grid.iter() {
+        picture.fill(pos, Rgb::WHITE.mix(Rgb::RED, *cost as f64 / 9.0));
+        picture.glyph(pos, char::from_digit(*cost, 10).unwrap_or('?'));
+    }
+    let mut pos = Pos { x: 0, y: 0 };
+    let mut path = vec![pos];
+    for step in &plan.steps {
+        pos = pos.mv(*step);
+        path.push(pos);
+    }
+    picture.path(path, Rgb::BLUE);
+    Ok(picture)
+}
+
+/// The cheapest plan from `from` to `to`, `accept_fn` tells whether a move from the first
+/// location to the second one is allowed.
+pub fn find_plan<F>(map: &Map, from: Pos, to: Pos, accept_fn: F) -> Result<Plan>
+where
+    F: Fn(&Loc, &Loc) -> bool,
+{
+    let starts = [Dir::East, Dir::South].map(|dir| Loc {
+        pos: from,
+        dir,
+        dir_count: 0,
+    });
+    // Every block loses at least the cheapest block's heat, so this never overestimates.
+    let min_cost = map.grid.iter().map(|(_, cost)| *cost).min().unwrap_or(0);
+    let mut last_frame_cost = 0;
+    let mut reached = None;
+    let visited = search::astar(
+        starts,
+        |loc| {
+            let mut next = Vec::new();
+            for dir in Dir::all() {
+                if loc.dir.opposite() == dir {
+                    continue;
+                }
+                let next_pos = loc.pos.mv(dir);
+                if let Some(cost) = map.cost(next_pos) {
+                    let next_loc = Loc {
+                        pos: next_pos,
+                        dir,
+                        dir_count: if dir == loc.dir { loc.dir_count + 1 } else { 1 },
+                    };
+                    if accept_fn(loc, &next_loc) {
+                        next.push((next_loc, cost));
+                    }
+                }
+            }
+            next
+        },
+        |loc| min_cost * loc.pos.manhattan(to) as u32,
+        |loc, visited| {
+            let cost = visited.cost(loc).unwrap();
+            if cost >= last_frame_cost + 10 {
+                last_frame_cost = cost;
+                animate::frame(|| search_frame(map, visited, loc));
+            }
+            if loc.pos == to {
+                reached = Some(*loc);
+                return Flow::Stop;
+            }
+            Flow::Continue
+        },
+    );
+
+    let to = reached.context("the crucible cannot reach the factory")?;
+    Ok(Plan::new(&visited, &to))
+}
+
+/// The blocks reached so far in blue, and the path to `current` in green.
+fn search_frame(map: &Map, visited: &Visited<Loc, u32>, current: &Loc) -> Frame {
+    let digit = |pos: Pos| char::from_digit(map.grid[pos], 10).unwrap_or('?');
+    let mut frame = Frame::new(map.grid.width(), map.grid.height());
+    for (pos, _) in map.grid.iter() {
+        frame.set(pos, digit(pos), None);
+    }
+    for (loc, _) in visited.iter() {
+        frame.set(loc.pos, digit(loc.pos), Some(Color::Blue));
+    }
+    for loc in visited.path(current).unwrap_or_default() {
+        frame.set(loc.pos, digit(loc.pos), Some(Color::Green));
+    }
+    let cost = visited.cost(current).unwrap_or_default();
+    frame.caption(format!("heat loss {cost}"))
+}
+
+/// The heat loss of each city block.
+pub struct Map {
+    pub grid: Grid<u32>,
+}
+
+impl Map {
+    pub fn width(&self) -> i64 {
+        self.grid.width() as i64
+    }
+    pub fn height(&self) -> i64 {
+        self.grid.height() as i64
+    }
+
+    pub fn cost(&self, pos: Pos) -> Option<u32> {
+        self.grid.get(pos).copied()
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
+        Ok(Map {
+            grid: Grid::parse(s, |ch| ch.to_digit(10))?,
+        })
+    }
+}
+
+/// A path, along with what it cost.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Plan {
+    pub cost: u32,
+    pub steps: Vec<Dir>,
+}
+
+impl Plan {
+    /// The plan of the search reaching `to`, which must have been visited.
+    fn new(visited: &Visited<Loc, u32>, to: &Loc) -> Self {
+        let path = visited.path(to).unwrap();
+        Plan {
+            cost: visited.cost(to).unwrap(),
+            // The start locations have no move towards them.
+            steps: path.iter().skip(1).map(|loc| loc.dir).collect(),
+        }
+    }
+}
+
+/// Where the crucible is, and how many blocks it moved straight towards `dir`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Loc {
+    pub pos: Pos,
+    pub dir: Dir,
+    pub dir_count: usize,
+}
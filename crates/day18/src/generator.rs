@@ -0,0 +1,68 @@
+//! Random dig plans.
+
+use generate::{Region, Rng};
+use geometry::{Dir, Pos};
+
+/// A dig plan around a random region of about `size` by `size` cells.
+///
+/// The region is stretched with random row heights and column widths, small ones for the
+/// plan and large ones for the plan hidden in the colors.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let cells = size.max(1);
+    let region = Region::generate(rng, cells, cells);
+    let corners = region.boundary();
+
+    // Runs of unit steps in the same direction, as the lines of the corner grid they cross.
+    let mut runs: Vec<(Dir, Vec<Pos>)> = Vec::new();
+    for (i, &corner) in corners.iter().enumerate() {
+        let next = corners[(i + 1) % corners.len()];
+        let dir = Dir::all()
+            .into_iter()
+            .find(|dir| corner.mv(*dir) == next)
+            .unwrap();
+        // A step covers the row or column between the two corners.
+        let line = Pos::new(corner.x.min(next.x), corner.y.min(next.y));
+        match runs.last_mut() {
+            Some((last, lines)) if *last == dir => lines.push(line),
+            _ => runs.push((dir, vec![line])),
+        }
+    }
+
+    let max_color = 0xfffff / (cells as u64 + 1);
+    let lengths = |rng: &mut Rng, range: std::ops::RangeInclusive<u64>| {
+        let rows = (0..=cells)
+            .map(|_| rng.range(range.clone()))
+            .collect::<Vec<_>>();
+        let cols = (0..=cells)
+            .map(|_| rng.range(range.clone()))
+            .collect::<Vec<_>>();
+        move |dir: Dir, lines: &[Pos]| -> u64 {
+            lines
+                .iter()
+                .map(|line| match dir {
+                    Dir::North | Dir::South => rows[line.x as usize],
+                    Dir::East | Dir::West => cols[line.y as usize],
+                })
+                .sum()
+        }
+    };
+    let length = lengths(rng, 2..=6);
+    let color = lengths(rng, 1000..=max_color.max(1000));
+
+    runs.iter()
+        .map(|(dir, lines)| {
+            let (name, digit) = match dir {
+                Dir::East => ('R', 0),
+                Dir::South => ('D', 1),
+                Dir::West => ('L', 2),
+                Dir::North => ('U', 3),
+            };
+            format!(
+                "{name} {} (#{:05x}{digit})",
+                length(*dir, lines),
+                color(*dir, lines)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
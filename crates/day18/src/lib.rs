@@ -0,0 +1,283 @@
+//! Day 18: Lavaduct Lagoon
+
+pub mod generator;
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+};
+
+use anyhow::{Context, Result};
+use geometry::{BoundingBox, Dir, Pos};
+use math::Overflow;
+use nom::{
+    character::complete::char,
+    combinator::{consumed, map},
+    error::context,
+    multi::fold_many_m_n,
+    sequence::{delimited, pair, preceded, tuple},
+};
+use parsing::{lines, mapped_char, number, one_of, tag, PResult, ParseError, Source};
+use render::{Picture, Rgb};
+use solution::{Part, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Plan;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<usize> {
+        Ok(dig(input).dug.len())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<i64> {
+        lagoon_from_colors(input).context("the lagoon is too large")
+    }
+}
+
+/// Digs the trench cell by cell, then its interior.
+pub fn dig(plan: &Plan) -> Dig {
+    let mut dig: Dig = Default::default();
+    dig.dug.insert(Default::default());
+
+    for step in plan.steps.iter() {
+        for _ in 0..step.length {
+            dig.digger = dig.digger.mv(step.dir);
+            dig.dug.insert(dig.digger);
+        }
+    }
+
+    dig.fill();
+
+    dig
+}
+
+/// The lagoon of the first part, the trench drawn in the colors of its steps.
+///
+/// The lagoon of the second part is far too large to be drawn.
+pub fn picture(plan: &Plan, part: Part) -> Result<Picture> {
+    if part == Part::Two {
+        return Err(anyhow::anyhow!(
+            "the lagoon of part 2 is too large to be drawn"
+        ));
+    }
+
+    let dig = dig(plan);
+    let bbox = dig.bounding_box();
+    let mut picture = Picture::new(bbox.width() as usize, bbox.height() as usize);
+    for pos in &dig.dug {
+        picture.fill(*pos - bbox.min, Rgb::GRAY);
+    }
+    let mut digger = Pos::default();
+    for step in &plan.steps {
+        let color = Rgb::from_hex(&format!("#{}", step.color.hex)).unwrap_or(Rgb::BLACK);
+        for _ in 0..step.length {
+            digger = digger.mv(step.dir);
+            picture.fill(digger - bbox.min, color);
+        }
+    }
+    Ok(picture)
+}
+
+#[derive(Debug, Default)]
+pub struct Dig {
+    pub dug: HashSet<Pos>,
+    pub digger: Pos,
+}
+
+impl Dig {
+    /// Digs out every cell enclosed by the trench.
+    pub fn fill(&mut self) {
+        let bbox = self.bounding_box();
+        let mut new_digs = HashSet::new();
+
+        for x in bbox.min.x..=bbox.max.x {
+            let mut inside = false;
+            for y in bbox.min.y..=bbox.max.y {
+                if self.dug.contains(&Pos { x, y }) {
+                    if self.dug.contains(&Pos { x: x - 1, y }) {
+                        inside = !inside;
+                    }
+                } else if inside {
+                    new_digs.insert(Pos { x, y });
+                }
+            }
+        }
+
+        self.dug.extend(new_digs);
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::from_points(self.dug.iter().copied()).unwrap_or(BoundingBox::new(self.digger))
+    }
+}
+
+impl Display for Dig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bbox = self.bounding_box();
+        for x in bbox.min.x..=bbox.max.x {
+            for y in bbox.min.y..=bbox.max.y {
+                if self.dug.contains(&Pos { x, y }) {
+                    f.write_char('#')?;
+                } else {
+                    f.write_char('.')?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+const DIRS: [(&str, Dir); 4] = [
+    ("L", Dir::West),
+    ("R", Dir::East),
+    ("U", Dir::North),
+    ("D", Dir::South),
+];
+
+/// The direction hidden in the last digit of a color.
+fn hidden_dir(digit: char) -> Option<Dir> {
+    match digit {
+        '0' => Some(Dir::East),
+        '1' => Some(Dir::South),
+        '2' => Some(Dir::West),
+        '3' => Some(Dir::North),
+        _ => None,
+    }
+}
+
+/// A color like `70c710`, hiding a length in its first five digits and a direction in
+/// the last one.
+fn color(input: &str) -> PResult<'_, Color> {
+    let digit = mapped_char("a hexadecimal digit", |ch| ch.to_digit(16));
+    let length = fold_many_m_n(5, 5, digit, || 0, |acc, d| acc * 16 + i64::from(d));
+    let dir = mapped_char("a direction digit from 0 to 3", hidden_dir);
+    map(consumed(pair(length, dir)), |(hex, (length, dir))| Color {
+        hex: hex.to_string(),
+        dir,
+        length,
+    })(input)
+}
+
+/// A step like `R 6 (#70c710)`.
+fn step(input: &str) -> PResult<'_, Step> {
+    let color = delimited(tag("(#"), context("a color like 70c710", color), char(')'));
+    let step = tuple((
+        one_of(&DIRS),
+        preceded(char(' '), number),
+        preceded(char(' '), color),
+    ));
+    map(step, |(dir, length, color)| Step { dir, length, color })(input)
+}
+
+pub fn parse(input: &str) -> Result<Plan, ParseError> {
+    let steps = Source::new(input).parse(lines(step))?;
+    Ok(Plan { steps })
+}
+
+/// The dig plan.
+#[derive(Debug)]
+pub struct Plan {
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub dir: Dir,
+    pub length: i64,
+    pub color: Color,
+}
+
+impl Step {
+    /// The actual step, hidden in the hexadecimal color code.
+    pub fn decode_color(&self) -> Self {
+        Self {
+            dir: self.color.dir,
+            length: self.color.length,
+            color: self.color.clone(),
+        }
+    }
+}
+
+/// The color of a step, with the step it hides.
+#[derive(Debug, Clone)]
+pub struct Color {
+    pub hex: String,
+    pub dir: Dir,
+    pub length: i64,
+}
+
+/// Size of the lagoon once the steps are decoded from their colors.
+pub fn lagoon_from_colors(plan: &Plan) -> Result<i64, Overflow> {
+    let steps = plan
+        .steps
+        .iter()
+        .map(|s| s.decode_color())
+        .collect::<Vec<_>>();
+    let mut pos: Pos = Default::default();
+    let mut edges = Vec::with_capacity(steps.len());
+    edges.push(pos);
+    for step in steps {
+        pos = pos.mv_at(step.dir, step.length);
+        edges.push(pos);
+    }
+
+    picks(&edges)
+}
+
+/// Area of the polygon with the given vertices, by the shoelace formula.
+pub fn shoelace(edges: &[Pos]) -> Result<i64, Overflow> {
+    let edges_next = edges.iter().skip(1);
+    let last = vec![(&edges[edges.len() - 1], &edges[0])];
+    // Twice the area, the trapezoids being summed before halving.
+    let twice = edges
+        .iter()
+        .zip(edges_next)
+        .chain(last)
+        .try_fold(0, |acc, (i, i_plus_one)| {
+            let trapezoid =
+                math::mul(math::add(i.x, i_plus_one.x)?, math::sub(i_plus_one.y, i.y)?)?;
+            math::add(acc, trapezoid)
+        })?;
+    Ok((twice / 2).abs())
+}
+
+/// Number of cells covered by the polygon, its boundary included, by Pick's theorem.
+pub fn picks(edges: &[Pos]) -> Result<i64, Overflow> {
+    let i = shoelace(edges)?;
+    let b = math::sum(
+        edges
+            .iter()
+            .zip(edges.iter().skip(1))
+            .map(|(a, b)| a.manhattan(*b)),
+    )?;
+    let b = i64::try_from(b).map_err(|_| Overflow)?;
+
+    math::add(math::add(i, b / 2)?, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_step() {
+        let plan = parse("R 6 (#70c710)\nD 5 (#0dc571)\n").unwrap();
+        let decoded = plan.steps[0].decode_color();
+        assert_eq!((Dir::East, 461937), (decoded.dir, decoded.length));
+        let decoded = plan.steps[1].decode_color();
+        assert_eq!((Dir::South, 56407), (decoded.dir, decoded.length));
+
+        let err = parse("R 6 (#70c710)\nR 6 (#70c71f)\n").unwrap_err();
+        assert_eq!((2, 12), (err.line, err.col));
+        assert_eq!("f", err.snippet);
+        assert_eq!("a direction digit from 0 to 3", err.expected);
+    }
+}
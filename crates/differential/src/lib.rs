@@ -0,0 +1,250 @@
+//! Differential testing: an optimized solver checked against an obvious one.
+//!
+//! Both are run on many small generated inputs. The first input they disagree on is then
+//! shrunk, as long as they keep disagreeing, so that the reported input is small enough to be
+//! debugged by hand. A panic counts as an answer, so a solver panicking where the other does
+//! not is a divergence too.
+
+use std::{
+    env,
+    fmt::{Debug, Display},
+};
+
+pub use generate::Rng;
+
+/// Environment variable overriding the seed of the generated inputs.
+pub const SEED_VAR: &str = "AOC_DIFF_SEED";
+/// Environment variable overriding the number of generated inputs.
+pub const CASES_VAR: &str = "AOC_DIFF_CASES";
+
+const DEFAULT_SEED: u64 = 2023;
+/// Gives up shrinking after that many smaller divergent inputs, to stay out of loops.
+const MAX_SHRINKS: usize = 10_000;
+
+/// An input the two solvers disagree on.
+#[derive(Debug)]
+pub struct Divergence<T, A> {
+    /// The first divergent input, as generated.
+    pub original: T,
+    /// The smallest divergent input found by shrinking [`original`](Self::original).
+    pub input: T,
+    /// What the reference solver answered on [`input`](Self::input), or how it panicked.
+    pub expected: Result<A, String>,
+    pub got: Result<A, String>,
+    /// Seed and index of the generated input, to generate it again.
+    pub seed: u64,
+    pub case: usize,
+}
+
+impl<T: Debug, A: Debug> Display for Divergence<T, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "the solvers diverge on case {} of seed {} ({SEED_VAR}={})",
+            self.case, self.seed, self.seed
+        )?;
+        writeln!(f, "input: {:?}", self.input)?;
+        writeln!(f, "expected: {:?}", self.expected)?;
+        writeln!(f, "got: {:?}", self.got)?;
+        write!(f, "shrunk from: {:?}", self.original)
+    }
+}
+
+/// How to generate inputs and compare two solvers on them.
+pub struct Check<T, G, S> {
+    cases: usize,
+    seed: u64,
+    generate: G,
+    shrink: S,
+    _input: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, G, S> Check<T, G, S>
+where
+    T: Clone,
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+{
+    /// Checks `cases` inputs from `generate`, unless [`CASES_VAR`] says otherwise.
+    ///
+    /// `shrink` lists inputs a bit smaller than the one it is given, the smaller ones first.
+    pub fn new(cases: usize, generate: G, shrink: S) -> Self {
+        Self {
+            cases: env::var(CASES_VAR)
+                .ok()
+                .and_then(|cases| cases.parse().ok())
+                .unwrap_or(cases),
+            seed: env::var(SEED_VAR)
+                .ok()
+                .and_then(|seed| seed.parse().ok())
+                .unwrap_or(DEFAULT_SEED),
+            generate,
+            shrink,
+            _input: Default::default(),
+        }
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Compares `optimized` to `reference` on every generated input.
+    pub fn run<A: PartialEq>(
+        &self,
+        reference: impl Fn(&T) -> A,
+        optimized: impl Fn(&T) -> A,
+    ) -> Result<(), Divergence<T, A>> {
+        self.find(&reference, &optimized)
+    }
+
+    /// Same as [`run`](Self::run), panicking with the divergence.
+    pub fn assert_same<A: PartialEq + Debug>(
+        &self,
+        reference: impl Fn(&T) -> A,
+        optimized: impl Fn(&T) -> A,
+    ) where
+        T: Debug,
+    {
+        if let Err(divergence) = self.run(reference, optimized) {
+            panic!("{divergence}");
+        }
+    }
+
+    fn find<A: PartialEq>(
+        &self,
+        reference: &impl Fn(&T) -> A,
+        optimized: &impl Fn(&T) -> A,
+    ) -> Result<(), Divergence<T, A>> {
+        let diverges = |input: &T| {
+            let expected = answer(reference, input);
+            let got = answer(optimized, input);
+            (expected != got).then_some((expected, got))
+        };
+
+        let mut rng = Rng::new(self.seed);
+        for case in 0..self.cases {
+            let original = (self.generate)(&mut rng);
+            let Some(mut answers) = diverges(&original) else {
+                continue;
+            };
+
+            let mut input = original.clone();
+            for _ in 0..MAX_SHRINKS {
+                let smaller = (self.shrink)(&input)
+                    .into_iter()
+                    .find_map(|smaller| Some((diverges(&smaller)?, smaller)));
+                let Some((smaller_answers, smaller)) = smaller else {
+                    break;
+                };
+                answers = smaller_answers;
+                input = smaller;
+            }
+
+            let (expected, got) = answers;
+            return Err(Divergence {
+                original,
+                input,
+                expected,
+                got,
+                seed: self.seed,
+                case,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The answer of `solver`, or its panic message.
+///
+/// Panics are part of the divergences, not printed for every input tried.
+fn answer<T, A>(solver: &impl Fn(&T) -> A, input: &T) -> Result<A, String> {
+    solution::catch_silently(|| solver(input)).map_err(|payload| {
+        match payload.downcast_ref::<&str>() {
+            Some(msg) => msg.to_string(),
+            None => payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_default(),
+        }
+    })
+}
+
+/// Shrinks a list: without one of its items, then with one item shrunk by `shrink_item`.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut smaller = (0..items.len())
+        .map(|i| [&items[..i], &items[i + 1..]].concat())
+        .collect::<Vec<_>>();
+    for (i, item) in items.iter().enumerate() {
+        for shrunk in shrink_item(item) {
+            let mut items = items.to_vec();
+            items[i] = shrunk;
+            smaller.push(items);
+        }
+    }
+    smaller
+}
+
+/// Numbers closer to zero than `n`: zero, its half, and the number right below.
+pub fn shrink_number(n: u64) -> Vec<u64> {
+    let mut smaller = Vec::new();
+    for candidate in [0, n / 2, n.saturating_sub(1)] {
+        if candidate < n && !smaller.contains(&candidate) {
+            smaller.push(candidate);
+        }
+    }
+    smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums the numbers, wrongly as soon as there is a 7 or more than 3 numbers.
+    fn broken_sum(numbers: &[u64]) -> u64 {
+        if numbers.len() > 3 {
+            panic!("too many numbers");
+        }
+        numbers
+            .iter()
+            .map(|&n| if n >= 7 { n + 1 } else { n })
+            .sum()
+    }
+
+    fn sum(numbers: &[u64]) -> u64 {
+        numbers.iter().sum()
+    }
+
+    #[test]
+    fn test_check() {
+        let generate = |rng: &mut Rng| {
+            (0..rng.range(0..=5))
+                .map(|_| rng.range(0..=100))
+                .collect::<Vec<_>>()
+        };
+        let shrink = |numbers: &Vec<u64>| shrink_vec(numbers, |&n| shrink_number(n));
+
+        let divergence = Check::new(100, generate, shrink)
+            .seed(1)
+            .run(|n| sum(n), |n| broken_sum(n))
+            .unwrap_err();
+        assert_eq!(1, divergence.seed);
+        assert!(divergence.to_string().contains("shrunk from: "));
+        // Either a single number just above the limit, or the smallest panicking list.
+        match divergence.got {
+            Ok(got) => {
+                assert_eq!(vec![7], divergence.input);
+                assert_eq!((Ok(7), 8), (divergence.expected, got));
+            }
+            Err(msg) => {
+                assert_eq!(vec![0, 0, 0, 0], divergence.input);
+                assert_eq!("too many numbers", msg);
+            }
+        }
+
+        Check::new(100, generate, shrink)
+            .run(|n| sum(n), |n| sum(n))
+            .unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+//! Building blocks of the days' input generators.
+//!
+//! Generated inputs only depend on the seed of the [`Rng`], so that any input can be
+//! generated again from the seed it was reported with.
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use geometry::{Dir, Pos};
+
+/// A small pseudo-random generator (SplitMix64), good enough to generate inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index in `0..len`, `len` must not be zero.
+    pub fn below(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// `true` once in `n` times.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+
+    pub fn pick<'a, T>(&mut self, choices: &'a [T]) -> &'a T {
+        &choices[self.below(choices.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Some of the cells of a grid, in one piece and without holes, no two of them touching
+/// only by a corner: its boundary is then a single loop that never touches itself.
+#[derive(Debug, Clone)]
+pub struct Region {
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+}
+
+impl Region {
+    /// A random region covering about half of a `width` by `height` grid.
+    pub fn generate(rng: &mut Rng, width: usize, height: usize) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
+        let mut region = Self {
+            width,
+            height,
+            cells: vec![false; width * height],
+        };
+        let start = Pos::new(rng.below(height) as i64, rng.below(width) as i64);
+        region.set(start, true);
+
+        let target = (width * height).div_ceil(2);
+        let mut size = 1;
+        for _ in 0..20 * width * height {
+            if size >= target {
+                break;
+            }
+            let frontier = region
+                .positions()
+                .filter(|&pos| {
+                    !region.contains(pos) && pos.neighbors().iter().any(|&n| region.contains(n))
+                })
+                .collect::<Vec<_>>();
+            let pos = *rng.pick(&frontier);
+            region.set(pos, true);
+            if region.is_simple() {
+                size += 1;
+            } else {
+                region.set(pos, false);
+            }
+        }
+
+        region
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the cell at `pos` is in the region, cells out of the grid never are.
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index(pos).is_some_and(|i| self.cells[i])
+    }
+
+    /// The corners of the boundary, one unit step apart, clockwise.
+    ///
+    /// The cell at `(x, y)` is the square between the corners `(x, y)` and `(x + 1, y + 1)`.
+    pub fn boundary(&self) -> Vec<Pos> {
+        // Each side between a cell of the region and one out of it, the region on its right.
+        let mut next = HashMap::new();
+        for pos in self.positions().filter(|&pos| self.contains(pos)) {
+            let [top_left, top_right, bottom_right, bottom_left] = [
+                pos,
+                pos.mv(Dir::East),
+                pos + Pos::new(1, 1),
+                pos.mv(Dir::South),
+            ];
+            for (dir, from, to) in [
+                (Dir::North, top_left, top_right),
+                (Dir::East, top_right, bottom_right),
+                (Dir::South, bottom_right, bottom_left),
+                (Dir::West, bottom_left, top_left),
+            ] {
+                if !self.contains(pos.mv(dir)) {
+                    next.insert(from, to);
+                }
+            }
+        }
+
+        let Some(&start) = next.keys().min() else {
+            return Vec::new();
+        };
+        let mut corners = vec![start];
+        let mut corner = next[&start];
+        while corner != start {
+            corners.push(corner);
+            corner = next[&corner];
+        }
+        corners
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height * width).map(move |i| Pos::new((i / width) as i64, (i % width) as i64))
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        (x < self.height && y < self.width).then_some(x * self.width + y)
+    }
+
+    fn set(&mut self, pos: Pos, value: bool) {
+        let i = self.index(pos).unwrap();
+        self.cells[i] = value;
+    }
+
+    /// Whether no cells touch only by a corner and every cell out of the region can reach
+    /// the outside of the grid.
+    fn is_simple(&self) -> bool {
+        let (height, width) = (self.height as i64, self.width as i64);
+        for x in -1..height {
+            for y in -1..width {
+                let [a, b, c, d] = [(0, 0), (0, 1), (1, 0), (1, 1)]
+                    .map(|(dx, dy)| self.contains(Pos::new(x + dx, y + dy)));
+                if (a && d && !b && !c) || (b && c && !a && !d) {
+                    return false;
+                }
+            }
+        }
+
+        // Flood fill from the frame of cells around the grid.
+        let framed = |pos: Pos| ((pos.x + 1) * (width + 2) + pos.y + 1) as usize;
+        let mut seen = vec![false; ((height + 2) * (width + 2)) as usize];
+        let mut stack = vec![Pos::new(-1, -1)];
+        seen[0] = true;
+        let mut reached = 1;
+        while let Some(pos) = stack.pop() {
+            for n in pos.neighbors() {
+                let in_frame = (-1..=height).contains(&n.x) && (-1..=width).contains(&n.y);
+                if in_frame && !self.contains(n) && !seen[framed(n)] {
+                    seen[framed(n)] = true;
+                    reached += 1;
+                    stack.push(n);
+                }
+            }
+        }
+        let inside = self.cells.iter().filter(|cell| **cell).count();
+        reached + inside == seen.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let numbers = (0..1000).map(|_| rng.range(3..=5)).collect::<Vec<_>>();
+        assert!(numbers.iter().all(|n| (3..=5).contains(n)));
+        assert!([3, 4, 5].iter().all(|n| numbers.contains(n)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        assert_eq!(u64::MAX, Rng::new(7).range(u64::MAX..=u64::MAX));
+
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!((0..10).collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn test_region() {
+        for seed in 0..20 {
+            let region = Region::generate(&mut Rng::new(seed), 7, 5);
+            assert!(region.is_simple());
+            let cells = region.positions().filter(|&p| region.contains(p)).count();
+            assert!(cells >= 18, "{cells} cells");
+
+            let boundary = region.boundary();
+            for (i, corner) in boundary.iter().enumerate() {
+                let next = boundary[(i + 1) % boundary.len()];
+                assert_eq!(1, corner.manhattan(next));
+                assert!(!boundary[i + 1..].contains(corner));
+            }
+        }
+
+        let single = Region::generate(&mut Rng::new(0), 1, 1);
+        assert_eq!(
+            vec![
+                Pos::new(0, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1),
+                Pos::new(1, 0)
+            ],
+            single.boundary()
+        );
+    }
+}
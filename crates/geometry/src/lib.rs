@@ -0,0 +1,284 @@
+//! Positions and directions on a 2D plane.
+//!
+//! Every solver uses the same convention: `x` is the row and grows downwards,
+//! `y` is the column and grows to the right, and `(0, 0)` is the top-left
+//! corner of the input.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position, `x` is the row and `y` the column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The position one step towards `dir`.
+    pub fn mv(self, dir: Dir) -> Pos {
+        self + dir.delta()
+    }
+
+    /// The position `n` steps towards `dir`.
+    pub fn mv_at(self, dir: Dir, n: i64) -> Pos {
+        self + dir.delta() * n
+    }
+
+    pub fn manhattan(self, other: Pos) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The four orthogonal neighbors, in the order of [`Dir::all`].
+    pub fn neighbors(self) -> [Pos; 4] {
+        Dir::all().map(|dir| self.mv(dir))
+    }
+
+    /// The eight surrounding positions, clockwise from the north.
+    pub fn neighbors8(self) -> [Pos; 8] {
+        let [north, east, south, west] = self.neighbors();
+        [
+            north,
+            north.mv(Dir::East),
+            east,
+            south.mv(Dir::East),
+            south,
+            south.mv(Dir::West),
+            west,
+            north.mv(Dir::West),
+        ]
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Pos) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, rhs: Pos) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Pos {
+    type Output = Pos;
+
+    fn mul(self, rhs: i64) -> Pos {
+        Pos::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Pos {
+        Pos::new(-self.x, -self.y)
+    }
+}
+
+/// A cardinal direction, north is towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    /// Every direction, clockwise from the north.
+    pub const fn all() -> [Dir; 4] {
+        [Dir::North, Dir::East, Dir::South, Dir::West]
+    }
+
+    /// The move of a single step in this direction.
+    pub const fn delta(self) -> Pos {
+        match self {
+            Dir::North => Pos::new(-1, 0),
+            Dir::East => Pos::new(0, 1),
+            Dir::South => Pos::new(1, 0),
+            Dir::West => Pos::new(0, -1),
+        }
+    }
+
+    pub const fn opposite(self) -> Dir {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+
+    /// The direction after a quarter turn counter-clockwise.
+    pub const fn turn_left(self) -> Dir {
+        match self {
+            Dir::North => Dir::West,
+            Dir::East => Dir::North,
+            Dir::South => Dir::East,
+            Dir::West => Dir::South,
+        }
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub const fn turn_right(self) -> Dir {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+}
+
+/// The smallest rectangle holding a set of positions, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl BoundingBox {
+    /// The box around `pos` alone.
+    pub fn new(pos: Pos) -> Self {
+        Self { min: pos, max: pos }
+    }
+
+    /// The box around every position, `None` when there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Pos>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bbox = BoundingBox::new(points.next()?);
+        for pos in points {
+            bbox.include(pos);
+        }
+        Some(bbox)
+    }
+
+    /// Grows the box, if needed, so that it holds `pos`.
+    pub fn include(&mut self, pos: Pos) {
+        self.min = Pos::new(self.min.x.min(pos.x), self.min.y.min(pos.y));
+        self.max = Pos::new(self.max.x.max(pos.x), self.max.y.max(pos.y));
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x) && (self.min.y..=self.max.y).contains(&pos.y)
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    /// The same box, grown by `n` in every direction.
+    pub fn expanded(&self, n: i64) -> Self {
+        Self {
+            min: self.min - Pos::new(n, n),
+            max: self.max + Pos::new(n, n),
+        }
+    }
+
+    /// Every position in the box, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (min, max) = (self.min, self.max);
+        (min.x..=max.x).flat_map(move |x| (min.y..=max.y).map(move |y| Pos::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundingBox, Dir, Pos};
+
+    #[test]
+    fn test_moves() {
+        let p = Pos::new(3, 4);
+        assert_eq!(Pos::new(2, 4), p.mv(Dir::North));
+        assert_eq!(Pos::new(3, 5), p.mv(Dir::East));
+        assert_eq!(Pos::new(3, -1), p.mv_at(Dir::West, 5));
+        assert_eq!(p, p.mv_at(Dir::South, 7).mv_at(Dir::North, 7));
+        assert_eq!(Pos::new(4, 6), p + Pos::new(1, 2));
+        assert_eq!(Pos::new(-3, -4), -p);
+        assert_eq!(7, p.manhattan(Pos::ORIGIN));
+        assert_eq!(12, Pos::new(-3, 2).manhattan(Pos::new(5, -2)));
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir::all() {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.opposite(), dir.turn_left().turn_left());
+            assert_eq!(dir.turn_right(), dir.turn_left().opposite());
+            assert_eq!(Pos::ORIGIN, dir.delta() + dir.opposite().delta());
+        }
+        assert_eq!(Dir::East, Dir::North.turn_right());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let p = Pos::new(1, 1);
+        assert_eq!(
+            [
+                Pos::new(0, 1),
+                Pos::new(1, 2),
+                Pos::new(2, 1),
+                Pos::new(1, 0)
+            ],
+            p.neighbors()
+        );
+        let around = p.neighbors8();
+        assert!(around
+            .iter()
+            .all(|n| n.x.abs_diff(1) <= 1 && n.y.abs_diff(1) <= 1));
+        assert!(!around.contains(&p));
+        assert_eq!(
+            8,
+            around
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert_eq!(None, BoundingBox::from_points([]));
+
+        let bbox =
+            BoundingBox::from_points([Pos::new(2, -1), Pos::new(-3, 4), Pos::new(0, 0)]).unwrap();
+        assert_eq!(Pos::new(-3, -1), bbox.min);
+        assert_eq!(Pos::new(2, 4), bbox.max);
+        assert_eq!((6, 6), (bbox.height(), bbox.width()));
+        assert!(bbox.contains(Pos::new(2, 4)));
+        assert!(!bbox.contains(Pos::new(3, 4)));
+        assert_eq!(36, bbox.positions().count());
+        assert_eq!(Some(&Pos::new(-3, -1)), bbox.positions().next().as_ref());
+        assert_eq!(64, bbox.expanded(1).positions().count());
+    }
+}
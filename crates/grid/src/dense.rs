@@ -0,0 +1,307 @@
+use std::{
+    fmt::{Display, Write},
+    ops::{Index, IndexMut},
+};
+
+use geometry::Pos;
+use nom::combinator::map;
+use parsing::{PResult, ParseError, Source};
+
+/// A grid storing every tile, row after row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid where every tile is `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows.
+    ///
+    /// # Panics
+    ///
+    /// If the rows do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "every row must have the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one tile per char, `f` returns `None` for chars that are not tiles.
+    pub fn parse(s: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Source::new(s).parse(Self::parser(f))
+    }
+
+    /// The parser behind [`Grid::parse`], for a grid within a larger input.
+    pub fn parser<'a>(
+        f: impl FnMut(char) -> Option<T>,
+    ) -> impl FnMut(&'a str) -> PResult<'a, Self> {
+        map(parsing::grid(f), Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        crate::in_bounds(self.width, self.height, pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            let offset = self.offset(pos);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// The orthogonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors8().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// Every position of the grid, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |x| (0..width).map(move |y| Pos::new(x as i64, y as i64)))
+    }
+
+    /// Every tile with its position, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first tile matching `pred`, row after row.
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.rows().nth(x).into_iter().flatten()
+    }
+
+    pub fn col(&self, y: usize) -> impl Iterator<Item = &T> {
+        let height = if y < self.width { self.height } else { 0 };
+        (0..height).map(move |x| &self.cells[x * self.width + y])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept a size of 0, the grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|y| self.col(y))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |pos| Pos::new(pos.y, pos.x))
+    }
+
+    /// The grid turned a quarter clockwise, the first column becomes the first row.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        // The tile landing at `pos` is found by turning the new grid back.
+        self.rearranged(self.height, self.width, |pos| {
+            crate::rotated_ccw(self.height, pos)
+        })
+    }
+
+    /// The grid turned a quarter counter-clockwise, the first row becomes the first column.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |pos| {
+            crate::rotated_cw(self.width, pos)
+        })
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            res.extend(row.iter().map(&f));
+            res.push('\n');
+        }
+        res
+    }
+
+    fn offset(&self, pos: Pos) -> usize {
+        pos.x as usize * self.width + pos.y as usize
+    }
+
+    /// A `width` by `height` grid where the tile at `pos` is taken from `source(pos)`.
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|x| (0..width).map(move |y| Pos::new(x as i64, y as i64)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Pos;
+
+    use crate::Grid;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[Pos::new(1, 2)]);
+        assert_eq!(None, grid.get(Pos::new(2, 0)));
+        assert_eq!(None, grid.get(Pos::new(0, -1)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let digits = |ch: char| ch.to_digit(10);
+        let err = Grid::parse("", digits).unwrap_err();
+        assert_eq!((1, 1), (err.line, err.col));
+        assert_eq!("a row of tiles", err.expected);
+        let err = Grid::parse("123\n45x\n", digits).unwrap_err();
+        assert_eq!((2, 3), (err.line, err.col));
+        assert_eq!("x", err.snippet);
+
+        let err = Grid::parse("123\n456\n78\n", digits).unwrap_err();
+        assert_eq!((3, 1), (err.line, err.col));
+        assert_eq!("78", err.snippet);
+        assert_eq!("a line of 3 tiles like the first one", err.expected);
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid = sample();
+        assert_eq!("def", grid.row(1).collect::<String>());
+        assert_eq!("be", grid.col(1).collect::<String>());
+        assert_eq!(0, grid.row(2).count());
+        assert_eq!(0, grid.col(3).count());
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            grid.cols()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        assert_eq!(
+            vec![Pos::new(0, 1), Pos::new(1, 0)],
+            grid.neighbors(Pos::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(5, grid.neighbors8(Pos::new(0, 1)).count());
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_ccw().to_string());
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+        assert_eq!(grid, grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = sample().map(|ch| *ch == 'e');
+        assert_eq!("...\n.#.\n", grid.render(|b| if *b { '#' } else { '.' }));
+        assert_eq!(Some(Pos::new(1, 1)), grid.find(|b| *b));
+    }
+}
@@ -0,0 +1,30 @@
+//! Two-dimensional grids of tiles, as found in most puzzle inputs.
+//!
+//! [`Grid`] stores every tile, [`SparseGrid`] only the interesting ones. Both
+//! are parsed from text with a closure mapping each char to a tile, and can be
+//! rendered back to text.
+//!
+//! Tiles are addressed with [`geometry::Pos`], `x` being the row and `y` the
+//! column, both starting at 0 in the top-left corner.
+
+use geometry::Pos;
+
+mod dense;
+mod sparse;
+
+pub use dense::Grid;
+pub use sparse::SparseGrid;
+
+fn in_bounds(width: usize, height: usize, pos: Pos) -> bool {
+    pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < height && (pos.y as usize) < width
+}
+
+/// Where `pos` lands once a `width` by `height` grid is turned a quarter clockwise.
+fn rotated_cw(height: usize, pos: Pos) -> Pos {
+    Pos::new(pos.y, height as i64 - 1 - pos.x)
+}
+
+/// Where `pos` lands once a `width` by `height` grid is turned a quarter counter-clockwise.
+fn rotated_ccw(width: usize, pos: Pos) -> Pos {
+    Pos::new(width as i64 - 1 - pos.y, pos.x)
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use geometry::Pos;
+use nom::combinator::map;
+use parsing::{PResult, ParseError, Source};
+
+/// A grid storing only some of its tiles, the others are empty.
+///
+/// A `SparseGrid<()>` is a set of positions with bounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty `width` by `height` grid.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses one tile per char, `empty` chars are not stored and `f` returns
+    /// `None` for the other chars that are not tiles.
+    pub fn parse(
+        s: &str,
+        empty: char,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Source::new(s).parse(Self::parser(empty, f))
+    }
+
+    /// The parser behind [`SparseGrid::parse`], for a grid within a larger input.
+    pub fn parser<'a>(
+        empty: char,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> impl FnMut(&'a str) -> PResult<'a, Self> {
+        let tile = move |ch| {
+            if ch == empty {
+                Some(None)
+            } else {
+                f(ch).map(Some)
+            }
+        };
+        map(parsing::grid(tile), |rows| {
+            let mut grid = Self::new(rows.first().map_or(0, Vec::len), rows.len());
+            for (x, row) in rows.into_iter().enumerate() {
+                for (y, tile) in row.into_iter().enumerate() {
+                    if let Some(tile) = tile {
+                        grid.cells.insert(Pos::new(x as i64, y as i64), tile);
+                    }
+                }
+            }
+            grid
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        crate::in_bounds(self.width, self.height, pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Stores a tile, returning the one it replaces.
+    ///
+    /// # Panics
+    ///
+    /// If `pos` is outside of the grid.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        assert!(self.in_bounds(pos), "{pos:?} is outside of the grid");
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Number of stored tiles.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The orthogonal neighbors of `pos` that are inside the grid, stored or not.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that are inside the grid, stored or not.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbors8().into_iter().filter(|n| self.in_bounds(*n))
+    }
+
+    /// The stored tiles with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(pos, t)| (*pos, t))
+    }
+
+    /// The stored tiles of row `x`, in no particular order.
+    pub fn row(&self, x: usize) -> impl Iterator<Item = (Pos, &T)> {
+        self.iter().filter(move |(pos, _)| pos.x == x as i64)
+    }
+
+    /// The stored tiles of column `y`, in no particular order.
+    pub fn col(&self, y: usize) -> impl Iterator<Item = (Pos, &T)> {
+        self.iter().filter(move |(pos, _)| pos.y == y as i64)
+    }
+
+    /// The grid mirrored along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.moved(self.height, self.width, |pos| Pos::new(pos.y, pos.x))
+    }
+
+    /// The grid turned a quarter clockwise, the first column becomes the first row.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.moved(self.height, self.width, |pos| {
+            crate::rotated_cw(self.height, pos)
+        })
+    }
+
+    /// The grid turned a quarter counter-clockwise, the first row becomes the first column.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.moved(self.height, self.width, |pos| {
+            crate::rotated_ccw(self.width, pos)
+        })
+    }
+
+    /// Renders the grid as text, one line per row, with `empty` where no tile is stored.
+    pub fn render(&self, empty: char, f: impl Fn(&T) -> char) -> String {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for x in 0..self.height {
+            for y in 0..self.width {
+                res.push(
+                    self.get(Pos::new(x as i64, y as i64))
+                        .map(&f)
+                        .unwrap_or(empty),
+                );
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    /// A `width` by `height` grid where each tile is moved to `target(pos)`.
+    fn moved(&self, width: usize, height: usize, target: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: self
+                .cells
+                .iter()
+                .map(|(pos, t)| (target(*pos), t.clone()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Pos;
+
+    use crate::SparseGrid;
+
+    fn sample() -> SparseGrid<char> {
+        SparseGrid::parse("#..\n.x.\n", '.', Some).unwrap()
+    }
+
+    fn render(grid: &SparseGrid<char>) -> String {
+        grid.render('.', |ch| *ch)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(2, grid.len());
+        assert_eq!(Some(&'x'), grid.get(Pos::new(1, 1)));
+        assert!(!grid.contains(Pos::new(0, 1)));
+        assert!(grid.in_bounds(Pos::new(0, 1)));
+        let err = SparseGrid::parse(".?", '.', |ch| (ch == '#').then_some(())).unwrap_err();
+        assert_eq!((1, 2), (err.line, err.col));
+        assert_eq!("?", err.snippet);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = sample();
+        assert_eq!("#..\n.x.\n", render(&grid));
+        assert_eq!("#.\n.x\n..\n", render(&grid.transpose()));
+        assert_eq!(".#\nx.\n..\n", render(&grid.rotate_cw()));
+        assert_eq!("..\n.x\n#.\n", render(&grid.rotate_ccw()));
+        assert_eq!(grid, grid.rotate_ccw().rotate_cw());
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let mut grid = sample();
+        grid.insert(Pos::new(1, 2), 'y');
+        assert_eq!(2, grid.row(1).count());
+        assert_eq!(1, grid.col(2).count());
+        assert_eq!(Some('y'), grid.remove(Pos::new(1, 2)));
+        assert_eq!(0, grid.col(2).count());
+    }
+}
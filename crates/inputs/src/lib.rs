@@ -0,0 +1,233 @@
+//! Puzzle inputs, stored outside of the crates so that everyone can keep their own.
+//!
+//! The store is a directory laid out as `<root>/<year>/<profile>/dayNN.txt`, a profile
+//! being one Advent of Code account. Missing inputs can be downloaded with a [`Fetcher`].
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+pub const YEAR: u16 = 2023;
+pub const DEFAULT_PROFILE: &str = "default";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable overriding the root of the store.
+pub const ROOT_VAR: &str = "AOC_INPUTS";
+/// Environment variable selecting the profile.
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+/// Environment variable overriding the server inputs are fetched from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable holding the session cookie used to fetch inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The `inputs` directory at the root of the workspace.
+pub fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("inputs")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Store {
+    root: PathBuf,
+    profile: String,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>, profile: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            profile: profile.into(),
+        }
+    }
+
+    /// The store configured by [`ROOT_VAR`] and [`PROFILE_VAR`], or the default one.
+    pub fn from_env() -> Self {
+        Self::new(
+            env::var_os(ROOT_VAR).map_or_else(default_root, PathBuf::from),
+            env::var(PROFILE_VAR).unwrap_or_else(|_| DEFAULT_PROFILE.to_string()),
+        )
+    }
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Where the input of `day` is, or would be, stored.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root
+            .join(YEAR.to_string())
+            .join(&self.profile)
+            .join(format!("day{day:02}.txt"))
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.path(day).is_file()
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        let path = self.path(day);
+        if !path.exists() {
+            return Err(anyhow::anyhow!(
+                "no input for day {day} of {YEAR} in profile {:?}: expected it at {}, \
+                 save it there or fetch it with `aoc fetch {day}`",
+                self.profile,
+                path.display()
+            ));
+        }
+        std::fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))
+    }
+
+    /// Stores `input` as the input of `day`, returns where it was written.
+    pub fn save(&self, day: u8, input: &str) -> Result<PathBuf> {
+        let path = self.path(day);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+        std::fs::write(&path, input)
+            .with_context(|| format!("could not write {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// The input of `day`, downloaded with `fetcher` and saved first when missing.
+    pub fn read_or_fetch(&self, day: u8, fetcher: &Fetcher) -> Result<String> {
+        if self.contains(day) {
+            return self.read(day);
+        }
+        let input = fetcher.fetch(day)?;
+        self.save(day, &input)?;
+        Ok(input)
+    }
+}
+
+/// The session cookie found in [`SESSION_VAR`].
+pub fn session_from_env() -> Result<String> {
+    env::var(SESSION_VAR)
+        .map_err(|_| anyhow::anyhow!("fetching inputs needs the session cookie in {SESSION_VAR}"))
+}
+
+/// Downloads inputs from the Advent of Code website, or anything serving the same paths.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// The fetcher configured by [`BASE_URL_VAR`] and [`SESSION_VAR`], the session is required.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(base_url, session_from_env()?))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    pub fn fetch(&self, day: u8) -> Result<String> {
+        let url = self.url(day);
+        let mut response = ureq::get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", "aoc-2023 input store")
+            .call()
+            .with_context(|| format!("could not fetch {url}"))?;
+        response
+            .body_mut()
+            .read_to_string()
+            .with_context(|| format!("could not read the input from {url}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tiny_http::{Header, Response, Server};
+
+    use super::*;
+
+    #[test]
+    fn test_store() {
+        let root = tempfile::tempdir().unwrap();
+        let store = Store::new(root.path(), "alice");
+        assert_eq!(root.path().join("2023/alice/day07.txt"), store.path(7));
+
+        let err = store.read(7).unwrap_err().to_string();
+        assert!(err.contains("no input for day 7 of 2023 in profile \"alice\""));
+        assert!(err.contains("aoc fetch 7"));
+
+        store.save(7, "32T3K 765\n").unwrap();
+        assert!(store.contains(7));
+        assert_eq!("32T3K 765\n", store.read(7).unwrap());
+        assert!(!Store::new(root.path(), "bob").contains(7));
+    }
+
+    /// Serves `requests` requests, answering with the input of the requested day when the
+    /// session is right.
+    fn mock_server(requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let handle = thread::spawn(move || {
+            let mut urls = Vec::new();
+            for request in server.incoming_requests().take(requests) {
+                let authorized = request.headers().iter().any(|header: &Header| {
+                    header.field.equiv("Cookie") && header.value.as_str() == "session=secret"
+                });
+                urls.push(request.url().to_string());
+                let day = request
+                    .url()
+                    .strip_prefix("/2023/day/")
+                    .and_then(|rest| rest.strip_suffix("/input"));
+                let response = match day {
+                    Some(day) if authorized => Response::from_string(format!("input of {}\n", day)),
+                    _ => {
+                        Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                    }
+                };
+                request.respond(response).unwrap();
+            }
+            urls
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, server) = mock_server(3);
+        let root = tempfile::tempdir().unwrap();
+        let store = Store::new(root.path(), DEFAULT_PROFILE);
+        let fetcher = Fetcher::new(format!("{base_url}/"), "secret");
+
+        assert_eq!("input of 5\n", store.read_or_fetch(5, &fetcher).unwrap());
+        assert_eq!("input of 5\n", store.read(5).unwrap());
+        // Already in the store, the server is not asked again.
+        assert_eq!("input of 5\n", store.read_or_fetch(5, &fetcher).unwrap());
+
+        assert_eq!("input of 12\n", fetcher.fetch(12).unwrap());
+
+        let err = Fetcher::new(&base_url, "wrong").fetch(1).unwrap_err();
+        assert!(format!("{err:#}").contains("could not fetch"));
+        assert!(!store.contains(1));
+
+        assert_eq!(
+            vec![
+                "/2023/day/5/input",
+                "/2023/day/12/input",
+                "/2023/day/1/input"
+            ],
+            server.join().unwrap()
+        );
+    }
+}
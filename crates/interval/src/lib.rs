@@ -0,0 +1,95 @@
+//! Half-open intervals of integers, sets of them and piecewise translations.
+//!
+//! [`RangeSet`] keeps a set of values as sorted disjoint intervals, with the
+//! usual set operations. [`PiecewiseMap`] translates each of its intervals
+//! somewhere else and leaves the other values alone, and can be composed with
+//! another one or applied to a whole set at once.
+
+use std::ops::{Add, Range, Sub};
+
+mod map;
+mod set;
+
+pub use map::PiecewiseMap;
+pub use set::RangeSet;
+
+/// The integer types intervals are made of, their default being zero.
+pub trait Bound: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+/// The values from `start` included to `end` excluded, empty unless `start < end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of values in the interval.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The values in both intervals, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The interval moved so that it starts at `start`.
+    pub fn moved_to(&self, start: T) -> Self {
+        Self::new(start, start + self.len())
+    }
+}
+
+impl<T: Bound> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every interval within `0..n`, empty ones included.
+    pub(crate) fn intervals(n: i64) -> impl Iterator<Item = Interval<i64>> + Clone {
+        (0..=n).flat_map(move |start| (0..=n).map(move |end| Interval::new(start, end)))
+    }
+
+    #[test]
+    fn test_interval() {
+        for a in intervals(6) {
+            let values = (0..6).filter(|&v| a.contains(v)).count() as i64;
+            assert_eq!(values, a.len(), "{a:?}");
+            assert_eq!(values == 0, a.is_empty(), "{a:?}");
+            for b in intervals(6) {
+                let both = (0..6).filter(|&v| a.contains(v) && b.contains(v));
+                let expected = both.collect::<Vec<_>>();
+                let got = a.intersection(&b);
+                assert_eq!(expected.is_empty(), got.is_none(), "{a:?} {b:?}");
+                if let Some(got) = got {
+                    assert_eq!(expected, (got.start..got.end).collect::<Vec<_>>());
+                }
+            }
+        }
+        assert_eq!(Interval::new(7, 10), Interval::from(2..5).moved_to(7));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{Bound, Interval, RangeSet};
+
+/// A function translating each of its intervals, the pieces, to another place, and leaving
+/// the values outside of them unchanged.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap<T> {
+    /// The pieces sorted by start, which do not overlap, with where their start goes.
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        Self { pieces: Vec::new() }
+    }
+}
+
+impl<T: Bound> PiecewiseMap<T> {
+    /// The identity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The pieces and where their start goes, sorted.
+    pub fn pieces(&self) -> &[(Interval<T>, T)] {
+        &self.pieces
+    }
+
+    /// Sends `from` to the interval of the same length starting at `to`.
+    ///
+    /// The values of `from` already in a piece keep going where that piece sends them.
+    pub fn insert(&mut self, from: Interval<T>, to: T) {
+        let covered = self
+            .pieces
+            .iter()
+            .map(|(piece, _)| *piece)
+            .collect::<RangeSet<_>>();
+        for part in RangeSet::from(from).difference(&covered).intervals() {
+            self.pieces.push((*part, to + (part.start - from.start)));
+        }
+        self.pieces.sort();
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        let i = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        match self.pieces.get(i) {
+            Some((piece, to)) if piece.contains(value) => *to + (value - piece.start),
+            _ => value,
+        }
+    }
+
+    /// Splits `interval` into the parts the map translates as a whole, along with their image.
+    pub fn split(&self, interval: Interval<T>) -> Vec<(Interval<T>, Interval<T>)> {
+        let mut parts = Vec::new();
+        let mut start = interval.start;
+        let first = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= interval.start);
+        for (piece, to) in &self.pieces[first..] {
+            if piece.start >= interval.end {
+                break;
+            }
+            if start < piece.start {
+                let unmapped = Interval::new(start, piece.start);
+                parts.push((unmapped, unmapped));
+            }
+            let Some(part) = piece.intersection(&interval) else {
+                continue;
+            };
+            parts.push((part, part.moved_to(*to + (part.start - piece.start))));
+            start = part.end;
+        }
+        if start < interval.end {
+            let unmapped = Interval::new(start, interval.end);
+            parts.push((unmapped, unmapped));
+        }
+        parts
+    }
+
+    /// The image of every value of `set`.
+    pub fn image(&self, set: &RangeSet<T>) -> RangeSet<T> {
+        set.intervals()
+            .iter()
+            .flat_map(|interval| self.split(*interval))
+            .map(|(_, image)| image)
+            .collect()
+    }
+
+    /// The map applying this one, then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut composed = Self::new();
+        for (piece, to) in &self.pieces {
+            for (part, image) in next.split(piece.moved_to(*to)) {
+                // Back to the values of this piece that land on `part`.
+                let from = part.moved_to(piece.start + (part.start - *to));
+                composed.pieces.push((from, image.start));
+            }
+        }
+        // The values this map leaves alone go wherever `next` sends them.
+        let covered = self
+            .pieces
+            .iter()
+            .map(|(piece, _)| *piece)
+            .collect::<RangeSet<_>>();
+        for (piece, to) in &next.pieces {
+            for part in RangeSet::from(*piece).difference(&covered).intervals() {
+                composed
+                    .pieces
+                    .push((*part, *to + (part.start - piece.start)));
+            }
+        }
+        composed.pieces.retain(|(piece, to)| piece.start != *to);
+        composed.pieces.sort();
+        composed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::intervals;
+
+    const N: i64 = 6;
+
+    /// Maps of up to two pieces within `0..N`, sent anywhere within `0..2 * N`.
+    fn maps() -> Vec<PiecewiseMap<i64>> {
+        let mut maps = vec![PiecewiseMap::new()];
+        for a in intervals(N).filter(|i| !i.is_empty()) {
+            for to in [0, 3, N] {
+                let mut map = PiecewiseMap::new();
+                map.insert(a, to);
+                maps.push(map.clone());
+                for b in intervals(N).filter(|b| !b.is_empty() && b.start >= a.end) {
+                    let mut map = map.clone();
+                    map.insert(b, 2 * N - b.len());
+                    maps.push(map);
+                }
+            }
+        }
+        maps
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut map = PiecewiseMap::new();
+        map.insert(Interval::new(2, 5), 10);
+        map.insert(Interval::new(0, 8), 20);
+        let values = (0..9).map(|v| map.apply(v)).collect::<Vec<_>>();
+        assert_eq!(vec![20, 21, 10, 11, 12, 25, 26, 27, 8], values);
+        assert_eq!(3, map.pieces().len());
+    }
+
+    #[test]
+    fn test_split_and_image() {
+        for map in maps() {
+            for interval in intervals(N) {
+                let parts = map.split(interval);
+                let values = parts
+                    .iter()
+                    .flat_map(|(part, _)| part.start..part.end)
+                    .collect::<Vec<_>>();
+                assert_eq!((interval.start..interval.end).collect::<Vec<_>>(), values);
+                for (part, image) in &parts {
+                    assert_eq!(part.len(), image.len());
+                    for v in part.start..part.end {
+                        assert_eq!(map.apply(v), image.start + (v - part.start), "{map:?}");
+                    }
+                }
+
+                let image = map.image(&interval.into());
+                for v in -1..=3 * N {
+                    let expected = (interval.start..interval.end).any(|u| map.apply(u) == v);
+                    assert_eq!(expected, image.contains(v), "{v} {interval:?} {map:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_then() {
+        let maps = maps();
+        for first in &maps {
+            for next in maps.iter().step_by(7) {
+                let composed = first.then(next);
+                for v in -1..=3 * N {
+                    assert_eq!(next.apply(first.apply(v)), composed.apply(v), "{v}");
+                }
+                assert!(composed
+                    .pieces()
+                    .windows(2)
+                    .all(|w| w[0].0.end <= w[1].0.start));
+            }
+        }
+    }
+}
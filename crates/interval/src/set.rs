@@ -0,0 +1,209 @@
+use crate::{Bound, Interval};
+
+/// A set of values, kept as sorted intervals which neither overlap nor touch.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Bound> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals of the set, sorted.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Adds the values of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            // Whichever ends first cannot meet anything else of the other set.
+            if i.end <= j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values of this set which are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut others = other.intervals.iter().peekable();
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            // The intervals of `other` ending before this one are of no use to the next ones.
+            while others.next_if(|j| j.end <= start).is_some() {}
+            for j in others.clone() {
+                if j.start >= interval.end {
+                    break;
+                }
+                if start < j.start {
+                    intervals.push(Interval::new(start, j.start));
+                }
+                start = start.max(j.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for RangeSet<T> {
+    /// The union of the intervals, in any order and overlapping or not.
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted = iter
+            .into_iter()
+            .filter(|i| !i.is_empty())
+            .collect::<Vec<_>>();
+        sorted.sort();
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for RangeSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::intervals;
+
+    /// Bit `v` tells whether `v` is in the set.
+    type Model = u32;
+
+    const N: i64 = 6;
+
+    fn model(set: &RangeSet<i64>) -> Model {
+        (0..N).filter(|&v| set.contains(v)).map(|v| 1 << v).sum()
+    }
+
+    /// Every set of values in `0..N`, from its model.
+    fn set(model: Model) -> RangeSet<i64> {
+        (0..N)
+            .filter(|v| model & 1 << v != 0)
+            .map(|v| Interval::new(v, v + 1))
+            .collect()
+    }
+
+    /// Whether the intervals are sorted, non-empty, and neither overlap nor touch.
+    fn is_normal(set: &RangeSet<i64>) -> bool {
+        set.intervals().iter().all(|i| !i.is_empty())
+            && set.intervals().windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    #[test]
+    fn test_from_iter() {
+        for a in intervals(N) {
+            for b in intervals(N) {
+                for c in intervals(N) {
+                    let set: RangeSet<_> = [a, b, c].into_iter().collect();
+                    assert!(is_normal(&set), "{set:?}");
+                    for v in -1..=N {
+                        let expected = a.contains(v) || b.contains(v) || c.contains(v);
+                        assert_eq!(expected, set.contains(v), "{v} in {a:?} {b:?} {c:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert() {
+        for m in 0..1 << N {
+            for i in intervals(N) {
+                let mut set = set(m);
+                set.insert(i);
+                assert!(is_normal(&set), "{set:?}");
+                assert_eq!(model(&set), m | model(&i.into()), "{m:b} + {i:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_operations() {
+        for a in 0..1 << N {
+            for b in 0..1 << N {
+                let (x, y) = (set(a), set(b));
+                for (result, expected) in [
+                    (x.union(&y), a | b),
+                    (x.intersection(&y), a & b),
+                    (x.difference(&y), a & !b),
+                ] {
+                    assert!(is_normal(&result), "{result:?}");
+                    assert_eq!(expected, model(&result), "{a:b} {b:b}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_min() {
+        assert_eq!(None, RangeSet::<u64>::new().min());
+        assert_eq!(Some(3), set(0b11001000).min());
+    }
+}
@@ -0,0 +1,314 @@
+//! Number theory: gcd and lcm, Bézout coefficients, the Chinese remainder
+//! theorem, modular arithmetic and integer square roots.
+//!
+//! Whatever could overflow returns an [`Overflow`] error rather than wrapping
+//! around, the puzzles' answers easily outgrowing 64 bits when multiplied.
+//!
+//! The plain arithmetic of [`add`], [`sub`], [`mul`], [`sum`] and [`product`] is only
+//! checked with the `checked` feature, and otherwise wraps in release builds like the
+//! operators do.
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+/// A result too large for its integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The primitive integer types, which [`add`], [`sub`], [`mul`], [`sum`] and [`product`]
+/// work on.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+/// The unsigned integer types, which [`gcd`], [`lcm`] and [`isqrt`] work on.
+pub trait Unsigned: Integer {
+    fn isqrt(self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                fn isqrt(self) -> Self {
+                    <$t>::isqrt(self)
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// `a + b`, an [`Overflow`] error when it does not fit, with the `checked` feature.
+pub fn add<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    checked(a, b, T::checked_add, T::add)
+}
+
+/// `a - b`, an [`Overflow`] error when it does not fit, with the `checked` feature.
+pub fn sub<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    checked(a, b, T::checked_sub, T::sub)
+}
+
+/// `a * b`, an [`Overflow`] error when it does not fit, with the `checked` feature.
+pub fn mul<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    checked(a, b, T::checked_mul, T::mul)
+}
+
+/// The sum of `nums`, checked like [`add`].
+pub fn sum<T: Integer>(nums: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    nums.into_iter().try_fold(T::ZERO, add)
+}
+
+/// The product of `nums`, checked like [`mul`].
+pub fn product<T: Integer>(nums: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    nums.into_iter().try_fold(T::ONE, mul)
+}
+
+fn checked<T>(
+    a: T,
+    b: T,
+    checked: fn(T, T) -> Option<T>,
+    plain: fn(T, T) -> T,
+) -> Result<T, Overflow> {
+    if cfg!(feature = "checked") {
+        checked(a, b).ok_or(Overflow)
+    } else {
+        Ok(plain(a, b))
+    }
+}
+
+/// Greatest common divisor, `gcd(0, 0)` being 0.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `lcm(a, 0)` being 0.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Result<T, Overflow> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b).ok_or(Overflow)
+}
+
+/// Greatest common divisor of all of `nums`, 0 when there are none.
+pub fn gcd_all<T: Unsigned>(nums: &[T]) -> T {
+    nums.iter().fold(T::ZERO, |acc, &n| gcd(acc, n))
+}
+
+/// Least common multiple of all of `nums`, 1 when there are none.
+pub fn lcm_all<T: Unsigned>(nums: &[T]) -> Result<T, Overflow> {
+    nums.iter().try_fold(T::ONE, |acc, &n| lcm(acc, n))
+}
+
+/// The largest integer whose square is at most `n`.
+pub fn isqrt<T: Unsigned>(n: T) -> T {
+    n.isqrt()
+}
+
+/// Extended Euclid: `(g, x, y)` with `g` the non-negative gcd of `a` and `b`, and
+/// `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> Result<(i64, i64, i64), Overflow> {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    Ok((narrow(g)?, narrow(x)?, narrow(y)?))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+fn narrow(n: i128) -> Result<i64, Overflow> {
+    i64::try_from(n).map_err(|_| Overflow)
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "the modulus must be positive, not {m}");
+    let (g, x, _) = extended_gcd_wide(a.into(), m.into());
+    // Below `m` once reduced, so it fits.
+    (g == 1).then(|| x.rem_euclid(m.into()) as i64)
+}
+
+/// `base` to the power `exp`, modulo `m`.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    assert!(m > 0, "the modulus must be positive");
+    let m = u128::from(m);
+    let mut base = u128::from(base) % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    // Below `m`, so it fits.
+    result as u64
+}
+
+/// Chinese remainder theorem: the `x` such that `x ≡ r (mod m)` for every `(r, m)` of
+/// `congruences`, as `(x, l)` with `l` the lcm of the moduli and `x` in `0..l`.
+///
+/// The moduli need not be coprime, `None` meaning that the congruences contradict each other.
+/// Errors when `l` does not fit.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<Option<(i64, i64)>, Overflow> {
+    let mut solution = (0i128, 1i128);
+    for &(r, m) in congruences {
+        assert!(m > 0, "the modulus must be positive, not {m}");
+        let (x, l) = solution;
+        let (r, m) = (i128::from(r).rem_euclid(m.into()), i128::from(m));
+
+        // x + l * k ≡ r (mod m), solvable when the gcd of l and m divides r - x.
+        let (g, inverse, _) = extended_gcd_wide(l, m);
+        if (r - x) % g != 0 {
+            return Ok(None);
+        }
+        let step = m / g;
+        let k = ((r - x) / g % step * inverse).rem_euclid(step);
+        let lcm = l * step;
+        narrow(lcm)?;
+        solution = ((x + l * k).rem_euclid(lcm), lcm);
+    }
+    let (x, l) = solution;
+    Ok(Some((narrow(x)?, narrow(l)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(12u32, 18));
+        assert_eq!(7, gcd(0u64, 7));
+        assert_eq!(Ok(36), lcm(12u32, 18));
+        assert_eq!(Ok(0), lcm(0usize, 5));
+        assert_eq!(3, gcd_all(&[9u8, 12, 21]));
+        assert_eq!(Ok(60), lcm_all(&[3usize, 4, 5, 6]));
+        assert_eq!(Ok(1), lcm_all::<u64>(&[]));
+        assert_eq!(Err(Overflow), lcm_all(&[251u8, 241]));
+        assert_eq!(Ok(u64::MAX), lcm(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Ok(5), add(2u8, 3));
+        assert_eq!(Ok(-1), sub(2i32, 3));
+        assert_eq!(Ok(6), mul(2u64, 3));
+        assert_eq!(Ok(10), sum([1usize, 2, 3, 4]));
+        assert_eq!(Ok(24), product([1i64, 2, 3, 4]));
+        assert_eq!(Ok(0), sum::<u32>([]));
+        assert_eq!(Ok(1), product::<u32>([]));
+        if cfg!(feature = "checked") {
+            assert_eq!(Err(Overflow), add(250u8, 10));
+            assert_eq!(Err(Overflow), sub(2u32, 3));
+            assert_eq!(Err(Overflow), mul(i64::MIN, -1));
+            assert_eq!(Err(Overflow), sum([u64::MAX, 1]));
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(0, isqrt(0u32));
+        assert_eq!(3, isqrt(15u64));
+        assert_eq!(4, isqrt(16usize));
+        assert_eq!(u32::MAX as u64, isqrt(u64::MAX));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(Ok((2, -9, 47)), extended_gcd(240, 46));
+        assert_eq!(Ok((5, 0, -1)), extended_gcd(0, -5));
+        let (g, x, y) = extended_gcd(-35, 15).unwrap();
+        assert_eq!((5, -35 * x + 15 * y), (g, 5));
+        assert_eq!(Err(Overflow), extended_gcd(i64::MIN, 0));
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 8));
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(u64::MAX - 1, mod_pow(u64::MAX - 1, u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Ok(Some((23, 105))), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // Moduli sharing factors.
+        assert_eq!(Ok(Some((10, 12))), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(Ok(None), crt(&[(1, 6), (2, 4)]));
+        assert_eq!(Ok(Some((0, 1))), crt(&[]));
+        assert_eq!(Ok(Some((4, 5))), crt(&[(-1, 5)]));
+        let big = (1i64 << 40) + 15;
+        assert_eq!(Err(Overflow), crt(&[(1, big), (2, big + 2), (0, 7)]));
+    }
+}
@@ -0,0 +1,191 @@
+//! Memoization of recursive functions, counting how often the cache helped.
+//!
+//! A [`Memo`] is passed down the recursion instead of a bare `HashMap`: each
+//! call goes through [`Memo::get`], which only computes the values it has not
+//! seen yet, and hands itself back to the computation for the recursive calls.
+//!
+//! ```
+//! use memo::Memo;
+//!
+//! fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     memo.get(&n, |memo| match n {
+//!         0 | 1 => n,
+//!         _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+//!     })
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(12586269025, fibonacci(50, &mut memo));
+//! assert_eq!(51, memo.stats().misses);
+//! ```
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    ops::{Add, AddAssign},
+};
+
+/// A cache of the values of a function, by argument.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value for `key`, from the cache or else from `compute`, which gets the memo back to
+    /// recurse with.
+    ///
+    /// `key` may be borrowed, it is only turned into an owned key when its value is computed.
+    pub fn get<Q>(&mut self, key: &Q, compute: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key.to_owned(), value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+}
+
+/// How a [`Memo`] was used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Values found in the cache.
+    pub hits: usize,
+    /// Values computed.
+    pub misses: usize,
+    /// Values in the cache.
+    pub entries: usize,
+}
+
+impl Stats {
+    /// Share of the values found in the cache, 0 when none were asked for.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.entries
+        )
+    }
+}
+
+impl Add for Stats {
+    type Output = Stats;
+
+    fn add(self, other: Self) -> Self {
+        Stats {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+            entries: self.entries + other.entries,
+        }
+    }
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of ways to write `s` as a sequence of `words`.
+    fn splits(s: &str, words: &[&str], memo: &mut Memo<String, usize>) -> usize {
+        memo.get(s, |memo| {
+            if s.is_empty() {
+                return 1;
+            }
+            words
+                .iter()
+                .filter_map(|word| s.strip_prefix(word))
+                .map(|rest| splits(rest, words, memo))
+                .sum()
+        })
+    }
+
+    #[test]
+    fn test_get() {
+        let mut memo = Memo::new();
+        assert_eq!(7, splits("aaaa", &["a", "aa", "aaa"], &mut memo));
+        let stats = memo.stats();
+        assert_eq!(
+            Stats {
+                hits: 5,
+                misses: 5,
+                entries: 5
+            },
+            stats
+        );
+
+        assert_eq!(4, splits("aaa", &["a", "aa", "aaa"], &mut memo));
+        assert_eq!(6, memo.stats().hits);
+        assert_eq!(
+            "6 hits, 5 misses (54.5% hit rate), 5 entries",
+            memo.stats().to_string()
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut total = Stats::default();
+        assert_eq!(0.0, total.hit_rate());
+        total += Stats {
+            hits: 3,
+            misses: 1,
+            entries: 1,
+        };
+        total += Stats {
+            hits: 0,
+            misses: 4,
+            entries: 4,
+        };
+        assert_eq!(3, total.hits);
+        assert_eq!(5, total.entries);
+        assert_eq!(3.0 / 8.0, total.hit_rate());
+    }
+}
@@ -0,0 +1,452 @@
+use std::str::FromStr;
+
+use nom::{
+    character::complete::{char, digit1, not_line_ending, space0, space1},
+    combinator::{opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair},
+    Err, IResult, Parser,
+};
+
+/// The error of the parsers: what was expected, and the slice of the input found instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected<'a> {
+    /// The offending text, empty when something is missing.
+    pub span: &'a str,
+    pub expected: String,
+}
+
+/// What the parsers return, the rest of the input along with the value.
+pub type PResult<'a, T> = IResult<&'a str, T, Expected<'a>>;
+
+impl<'a> Expected<'a> {
+    pub fn new(span: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            span,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error about the token `input` starts with.
+    pub fn at(input: &'a str, expected: impl Into<String>) -> Self {
+        Self::new(token(input), expected)
+    }
+}
+
+/// The word or number `input` starts with, or else its first char, short of the line end.
+///
+/// Leading spaces are skipped, unless nothing follows them on the line.
+fn token(input: &str) -> &str {
+    let trimmed = input.trim_start_matches([' ', '\t']);
+    if trimmed.len() < input.len() && !matches!(trimmed.chars().next(), Some('\n' | '\r') | None) {
+        return token(trimmed);
+    }
+    let word = input
+        .find(|ch: char| !ch.is_alphanumeric())
+        .unwrap_or(input.len());
+    match input.chars().next() {
+        Some('\n' | '\r') | None => &input[..0],
+        Some(ch) if word == 0 => &input[..ch.len_utf8()],
+        Some(_) => &input[..word],
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a digit".to_string(),
+        ErrorKind::HexDigit => "a hexadecimal digit".to_string(),
+        ErrorKind::Alpha => "a letter".to_string(),
+        ErrorKind::AlphaNumeric => "a letter or a digit".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "a space".to_string(),
+        ErrorKind::CrLf => "the end of the line".to_string(),
+        ErrorKind::Eof => "the end of the input".to_string(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::at(input, describe(kind))
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, ch: char) -> Self {
+        Self::at(input, format!("{ch:?}"))
+    }
+
+    /// Of two alternatives, the one which got further, or both when they failed at once.
+    fn or(self, other: Self) -> Self {
+        let (this, that) = (self.span.as_ptr(), other.span.as_ptr());
+        if this == that {
+            Self::new(
+                self.span,
+                format!("{} or {}", self.expected, other.expected),
+            )
+        } else if this > that {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Expected<'a> {
+    /// Names what was expected when the parser failed right away, a deeper error being more
+    /// precise.
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if other.span.as_ptr() == input.as_ptr() {
+            Self::new(other.span, context)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Expected<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        Self::at(input, describe(kind))
+    }
+}
+
+/// `expected` itself.
+pub fn tag<'a>(expected: &'static str) -> impl FnMut(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((rest, &input[..expected.len()])),
+        None => {
+            // As much of the input as was expected, within the line.
+            let len = input
+                .char_indices()
+                .take(expected.chars().count())
+                .take_while(|(_, ch)| *ch != '\n')
+                .map(|(i, ch)| i + ch.len_utf8())
+                .last()
+                .unwrap_or(0);
+            Err(Err::Error(Expected::new(
+                &input[..len],
+                format!("{expected:?}"),
+            )))
+        }
+    }
+}
+
+/// The value paired with the first of `choices` the input starts with.
+pub fn one_of<'a, 'b, T: Clone>(
+    choices: &'b [(&'b str, T)],
+) -> impl FnMut(&'a str) -> PResult<'a, T> + 'b {
+    move |input: &'a str| {
+        for (name, value) in choices {
+            if let Some(rest) = input.strip_prefix(name) {
+                return Ok((rest, value.clone()));
+            }
+        }
+        let names = choices
+            .iter()
+            .map(|(name, _)| format!("{name:?}"))
+            .collect::<Vec<_>>();
+        Err(Err::Error(Expected::at(
+            input,
+            format!("one of {}", names.join(", ")),
+        )))
+    }
+}
+
+/// A char that `f` maps to a value, `expected` describing those chars.
+pub fn mapped_char<'a, T>(
+    expected: &'static str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        let value = input.chars().next().and_then(&mut f);
+        match value {
+            Some(value) => {
+                let len = input.chars().next().map_or(0, char::len_utf8);
+                Ok((&input[len..], value))
+            }
+            None => {
+                let len = input
+                    .chars()
+                    .next()
+                    .filter(|ch| *ch != '\n')
+                    .map_or(0, char::len_utf8);
+                Err(Err::Error(Expected::new(&input[..len], expected)))
+            }
+        }
+    }
+}
+
+/// A number, with a sign when `T` can be negative.
+pub fn number<'a, T: FromStr>(input: &'a str) -> PResult<'a, T> {
+    let digits: PResult<'a, &'a str> = recognize(pair(opt(char('-')), digit1))(input);
+    let Ok((rest, digits)) = digits else {
+        return Err(Err::Error(Expected::at(input, "a number")));
+    };
+    match digits.parse() {
+        Ok(n) => Ok((rest, n)),
+        // Digits all right, but too many of them or a misplaced sign.
+        Err(_) => Err(Err::Failure(Expected::new(digits, "a number"))),
+    }
+}
+
+/// Numbers separated by spaces, the first one possibly preceded by some.
+pub fn numbers<'a, T: FromStr>(input: &'a str) -> PResult<'a, Vec<T>> {
+    preceded(space0, separated_list1(space1, number))(input)
+}
+
+/// `item`s separated by `sep`, an item having to follow each separator.
+pub fn list<'a, T, S>(
+    mut sep: impl Parser<&'a str, S, Expected<'a>>,
+    mut item: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (mut input, first) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((next, _)) = sep.parse(input) {
+            let (rest, value) = item.parse(next).map_err(cut)?;
+            items.push(value);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// A `key: value` pair, the spaces after the colon skipped.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, Expected<'a>>,
+    value: impl Parser<&'a str, V, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, (K, V)> {
+    separated_pair(key, pair(char(':'), space0), value)
+}
+
+/// The length of the line ending `input` starts with, if any.
+fn line_ending(input: &str) -> Option<usize> {
+    if input.starts_with("\r\n") {
+        Some(2)
+    } else if input.starts_with('\n') {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// One `item` per line, up to a blank line or the end of the input.
+///
+/// Each item must take its whole line, and once the first line is read the next ones must
+/// parse too, so that errors point at the offending line rather than stopping the list.
+pub fn lines<'a, T>(
+    mut item: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| parse_lines(input, &mut item)
+}
+
+fn parse_lines<'a, T>(
+    input: &'a str,
+    item: &mut impl Parser<&'a str, T, Expected<'a>>,
+) -> PResult<'a, Vec<T>> {
+    let (mut input, first) = item.parse(input)?;
+    let mut items = vec![first];
+    loop {
+        if input.is_empty() {
+            break;
+        }
+        let Some(len) = line_ending(input) else {
+            return Err(Err::Failure(Expected::at(input, "the end of the line")));
+        };
+        let next = &input[len..];
+        if next.is_empty() || line_ending(next).is_some() {
+            break;
+        }
+        let (rest, value) = item.parse(next).map_err(cut)?;
+        items.push(value);
+        input = rest;
+    }
+    Ok((input, items))
+}
+
+/// `item`s separated by blank lines, once the first is read the next ones must parse too.
+pub fn blocks<'a, T>(
+    mut item: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (mut input, first) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Some(mut next) = line_ending(input).map(|len| &input[len..]) {
+            if line_ending(next).is_none() {
+                break;
+            }
+            while let Some(len) = line_ending(next) {
+                next = &next[len..];
+            }
+            if next.is_empty() {
+                break;
+            }
+            let (rest, value) = item.parse(next).map_err(cut)?;
+            items.push(value);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Rows of tiles, one per char, each row as wide as the first; `tile` returns `None` for the
+/// chars that are not tiles.
+pub fn grid<'a, T>(
+    mut tile: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> PResult<'a, Vec<Vec<T>>> {
+    move |input: &'a str| {
+        let mut width = None;
+        let mut row = |line: &'a str| {
+            let (rest, row) = not_line_ending(line)?;
+            if row.is_empty() {
+                return Err(Err::Error(Expected::at(row, "a row of tiles")));
+            }
+            let tiles = row
+                .char_indices()
+                .map(|(i, ch)| {
+                    tile(ch).ok_or_else(|| {
+                        Err::Failure(Expected::new(&row[i..i + ch.len_utf8()], "a known tile"))
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+
+            let expected = *width.get_or_insert(tiles.len());
+            if tiles.len() != expected {
+                return Err(Err::Failure(Expected::new(
+                    row,
+                    format!("a line of {expected} tiles like the first one"),
+                )));
+            }
+            Ok((rest, tiles))
+        };
+        parse_lines(input, &mut row)
+    }
+}
+
+/// Turns an error into a failure, which alternatives do not try to recover from.
+fn cut(err: Err<Expected<'_>>) -> Err<Expected<'_>> {
+    match err {
+        Err::Error(e) => Err::Failure(e),
+        err => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{branch::alt, combinator::value, error::context};
+
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(Ok(("", -3)), number::<i64>("-3"));
+        assert_eq!(
+            Err(Err::Error(Expected::new("x", "a number"))),
+            number::<u32>("x blue")
+        );
+        assert_eq!(
+            Err(Err::Failure(Expected::new("-3", "a number"))),
+            number::<u32>("-3")
+        );
+        assert_eq!(
+            Err(Err::Failure(Expected::new("99999999999", "a number"))),
+            number::<u32>("99999999999")
+        );
+        assert_eq!(Ok(("", vec![7, 15, 6])), numbers::<u32>("  7  15 6"));
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let color = |input| {
+            alt((
+                value(0, tag("red")),
+                value(1, tag("green")),
+                value(2, tag("blue")),
+            ))(input)
+        };
+        assert_eq!(Ok((" cubes", 1)), color("green cubes"));
+        assert_eq!(
+            Err(Err::Error(Expected::new(
+                "yel",
+                "\"red\" or \"green\" or \"blue\""
+            ))),
+            color("yellow")
+        );
+        assert_eq!(
+            Err(Err::Error(Expected::new("yel", "a color"))),
+            context("a color", color)("yellow")
+        );
+
+        let colors = [("red", 0), ("green", 1)];
+        assert_eq!(Ok(("", 1)), one_of(&colors)("green"));
+        assert_eq!(
+            Err(Err::Error(Expected::new(
+                "blue",
+                "one of \"red\", \"green\""
+            ))),
+            one_of(&colors)("blue,")
+        );
+        assert_eq!(
+            Ok(("", ("Time", vec![7u32, 15]))),
+            key_value(tag("Time"), numbers)("Time:   7 15")
+        );
+    }
+
+    #[test]
+    fn test_list() {
+        let mut groups = list(char(','), number::<u32>);
+        assert_eq!(Ok((" x", vec![1, 1, 3])), groups("1,1,3 x"));
+        assert_eq!(
+            Err(Err::Failure(Expected::new("x", "a number"))),
+            groups("1,x,3")
+        );
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let input = "1 2\n3\n\n4\n5 6\n";
+        let (rest, blocks) = blocks(lines(numbers::<u32>))(input).unwrap();
+        assert_eq!(
+            vec![vec![vec![1, 2], vec![3]], vec![vec![4], vec![5, 6]]],
+            blocks
+        );
+        assert_eq!("\n", rest);
+
+        let input = "1 2\n3 x\n4";
+        assert_eq!(
+            Err(Err::Failure(Expected::new("x", "the end of the line"))),
+            lines(numbers::<u32>)(input)
+        );
+        let input = "1 2\nx\n4";
+        assert_eq!(
+            Err(Err::Failure(Expected::new("x", "a number"))),
+            lines(numbers::<u32>)(input)
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let tile = |ch| match ch {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let (rest, rows) = grid(tile)("#.\n.#\n\n##").unwrap();
+        assert_eq!(vec![vec![true, false], vec![false, true]], rows);
+        assert_eq!("\n\n##", rest);
+
+        assert_eq!(
+            Err(Err::Failure(Expected::new("x", "a known tile"))),
+            grid(tile)("#.\n.x")
+        );
+        assert_eq!(
+            Err(Err::Failure(Expected::new(
+                "...",
+                "a line of 2 tiles like the first one"
+            ))),
+            grid(tile)("#.\n...")
+        );
+    }
+}
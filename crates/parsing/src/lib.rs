@@ -0,0 +1,209 @@
+//! Parsers for puzzle inputs, with errors pointing at the offending part.
+//!
+//! Parsers work on slices of the input, so an error only needs the slice it
+//! failed on to know its line and column. The parsers are [`nom`] ones, with
+//! combinators for the shapes puzzle inputs keep coming in: numbers, `key: value`
+//! lines, lists of lines, blocks separated by blank lines and grids of chars.
+//! [`Source`] wraps the text being parsed, runs them and builds the errors.
+
+use std::fmt::Display;
+
+use nom::Parser;
+
+mod combinators;
+
+pub use combinators::{
+    blocks, grid, key_value, lines, list, mapped_char, number, numbers, one_of, tag, Expected,
+    PResult,
+};
+
+/// Where and why an input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, in chars, of the offending text.
+    pub col: usize,
+    /// The offending text, empty when something is missing.
+    pub snippet: String,
+    /// What the parser was looking for.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error about `span`, a slice of `text`.
+    pub fn at(text: &str, span: &str, expected: impl Into<String>) -> Self {
+        let (line, col) = location(text, offset_of(text, span));
+        Self {
+            line,
+            col,
+            snippet: span.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// The error along with the offending line of `text`, the snippet underlined.
+    pub fn render(&self, text: &str) -> String {
+        let source_line = text.lines().nth(self.line - 1).unwrap_or_default();
+        let available = source_line.chars().count().saturating_sub(self.col - 1);
+        let width = self
+            .snippet
+            .lines()
+            .next()
+            .map(|s| s.chars().count())
+            .unwrap_or(0)
+            .clamp(1, available.max(1));
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        format!(
+            "{self}\n{gutter} |\n{number} | {source_line}\n{gutter} | {}{}",
+            " ".repeat(self.col - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.col, self.expected
+        )?;
+        if self.snippet.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The text being parsed, used to locate errors about its slices.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The empty slice at the very end of the text, to report missing parts.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    /// An error about `span`, a slice of the text.
+    pub fn error(&self, span: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.text, span, expected)
+    }
+
+    /// Parses the whole text with `parser`, but for trailing whitespace.
+    pub fn parse<T>(
+        &self,
+        mut parser: impl Parser<&'a str, T, Expected<'a>>,
+    ) -> Result<T, ParseError> {
+        let err = match parser.parse(self.text) {
+            Ok((rest, value)) if rest.trim_start().is_empty() => return Ok(value),
+            Ok((rest, _)) => Expected::at(rest.trim_start(), "the end of the input"),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e,
+            Err(nom::Err::Incomplete(_)) => Expected::new(self.end(), "more input"),
+        };
+        Err(self.error(err.span, err.expected))
+    }
+}
+
+/// Byte offset of `span` in `text`, `span` being a slice of it.
+fn offset_of(text: &str, span: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let at = span.as_ptr() as usize;
+    if at >= start && at + span.len() <= start + text.len() {
+        at - start
+    } else {
+        // Not an actual slice, the first occurrence is the best guess.
+        text.find(span).unwrap_or(text.len())
+    }
+}
+
+/// 1-based line and column of the byte `offset` of `text`.
+fn location(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let col = before.chars().rev().take_while(|ch| *ch != '\n').count() + 1;
+    (line, col)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{alpha1, char},
+        multi::separated_list1,
+        sequence::{preceded, separated_pair},
+    };
+
+    use crate::{key_value, lines, number, tag, Source};
+
+    const INPUT: &str = "Game 1: 3 red\nGame 2: x blue\n";
+
+    #[test]
+    fn test_location() {
+        let source = Source::new(INPUT);
+        let err = source.error(&INPUT[22..23], "a number");
+        assert_eq!((2, 9), (err.line, err.col));
+        assert_eq!("x", err.snippet);
+        assert_eq!("a number", err.expected);
+
+        let err = source.error(source.end(), "a game");
+        assert_eq!((3, 1), (err.line, err.col));
+        assert_eq!(
+            "line 3, column 1: expected a game, found nothing",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let source = Source::new(INPUT);
+        let cubes = separated_pair(number::<u32>, char(' '), alpha1);
+        let game = key_value(preceded(tag("Game "), number::<u32>), cubes);
+        let err = source.parse(lines(game)).unwrap_err();
+        assert_eq!((2, 9), (err.line, err.col));
+        assert_eq!(("x", "a number"), (&err.snippet[..], &err.expected[..]));
+
+        let source = Source::new("1,2,3\n\n");
+        assert_eq!(
+            vec![1, 2, 3],
+            source
+                .parse(separated_list1(char(','), number::<u8>))
+                .unwrap()
+        );
+        let err = Source::new("1,2;3").parse(separated_list1(char(','), number::<u8>));
+        let err = err.unwrap_err();
+        assert_eq!((1, 4), (err.line, err.col));
+        assert_eq!("the end of the input", err.expected);
+
+        let err = Source::new("Time: 7").parse(tag("Distance:")).unwrap_err();
+        assert_eq!(
+            ("Time: 7", "\"Distance:\""),
+            (&err.snippet[..], &err.expected[..])
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let source = Source::new(INPUT);
+        let err = source.error(&INPUT[22..26], "a color");
+        assert_eq!(
+            "line 2, column 9: expected a color, found \"x bl\"\n  |\n2 | Game 2: x blue\n  |         ^^^^",
+            err.render(INPUT)
+        );
+    }
+}
@@ -0,0 +1,340 @@
+//! Pictures of grid-based puzzle states, saved as SVG or PNG images.
+//!
+//! A [`Picture`] is a grid of tiles, each with a color and an optional glyph, with paths
+//! drawn through the centers of tiles on top of them. SVG images have the glyphs, PNG images
+//! only the colors and the paths.
+
+use std::{fmt::Write, fs, path::Path};
+
+use anyhow::{Context, Result};
+use geometry::Pos;
+
+/// A color, red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(0xff, 0xff, 0xff);
+    pub const BLACK: Rgb = Rgb(0x00, 0x00, 0x00);
+    pub const GRAY: Rgb = Rgb(0xb0, 0xb0, 0xb0);
+    pub const RED: Rgb = Rgb(0xd6, 0x27, 0x28);
+    pub const GREEN: Rgb = Rgb(0x2c, 0xa0, 0x2c);
+    pub const BLUE: Rgb = Rgb(0x1f, 0x77, 0xb4);
+    pub const YELLOW: Rgb = Rgb(0xff, 0xd7, 0x00);
+
+    /// Parses a color like `#70c710`.
+    pub fn from_hex(hex: &str) -> Option<Rgb> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// The color between `self`, at 0, and `other`, at 1.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tile {
+    color: Rgb,
+    glyph: Option<char>,
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    points: Vec<Pos>,
+    color: Rgb,
+}
+
+/// Size of a tile in an SVG image, in user units.
+const SVG_TILE: usize = 16;
+/// Largest side of a PNG image in pixels, tiles are shrunk to fit in it.
+const PNG_MAX_SIDE: usize = 1600;
+
+/// A `width` by `height` grid of tiles, white until painted.
+///
+/// Positions out of the grid are ignored, so that callers can paint whatever they have.
+#[derive(Debug, Clone)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+    lines: Vec<Line>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            tiles: vec![
+                Tile {
+                    color: Rgb::WHITE,
+                    glyph: None,
+                };
+                width * height
+            ],
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn fill(&mut self, pos: Pos, color: Rgb) {
+        if let Some(i) = self.index(pos) {
+            self.tiles[i].color = color;
+        }
+    }
+
+    /// Writes `glyph` on the tile, in SVG images only.
+    pub fn glyph(&mut self, pos: Pos, glyph: char) {
+        if let Some(i) = self.index(pos) {
+            self.tiles[i].glyph = Some(glyph);
+        }
+    }
+
+    /// Draws a line through the centers of `points`, in order.
+    pub fn path(&mut self, points: impl IntoIterator<Item = Pos>, color: Rgb) {
+        self.lines.push(Line {
+            points: points.into_iter().collect(),
+            color,
+        });
+    }
+
+    /// Saves the picture as an SVG or a PNG image, depending on the extension of `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => self.to_svg().into_bytes(),
+            Some("png") => self.to_png()?,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "can only save pictures as .svg or .png files, not {}",
+                    path.display()
+                ))
+            }
+        };
+        fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width * SVG_TILE, self.height * SVG_TILE);
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+            Rgb::WHITE.hex()
+        );
+
+        for (pos, tile) in self.tiles() {
+            let (x, y) = (pos.y as usize * SVG_TILE, pos.x as usize * SVG_TILE);
+            if tile.color != Rgb::WHITE {
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{SVG_TILE}" height="{SVG_TILE}" fill="{}"/>"#,
+                    tile.color.hex()
+                );
+            }
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<g font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+            SVG_TILE * 3 / 4
+        );
+        for (pos, tile) in self.tiles() {
+            if let Some(glyph) = tile.glyph {
+                let (x, y) = center(pos, SVG_TILE);
+                let _ = writeln!(svg, r#"<text x="{x}" y="{y}">{}</text>"#, escape(glyph));
+            }
+        }
+        let _ = writeln!(svg, "</g>");
+
+        for line in &self.lines {
+            let points = line
+                .points
+                .iter()
+                .map(|&pos| {
+                    let (x, y) = center(pos, SVG_TILE);
+                    format!("{x},{y}")
+                })
+                .collect::<Vec<_>>();
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round" stroke-linecap="round"/>"#,
+                points.join(" "),
+                line.color.hex(),
+                SVG_TILE / 4
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let tile = (PNG_MAX_SIDE / self.width.max(self.height).max(1)).clamp(1, SVG_TILE);
+        let (width, height) = (self.width * tile, self.height * tile);
+        let mut pixels = vec![0; width * height * 3];
+        let mut put = |x: usize, y: usize, color: Rgb| {
+            if x < width && y < height {
+                let i = (y * width + x) * 3;
+                pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        };
+
+        for (pos, t) in self.tiles() {
+            for dy in 0..tile {
+                for dx in 0..tile {
+                    put(
+                        pos.y as usize * tile + dx,
+                        pos.x as usize * tile + dy,
+                        t.color,
+                    );
+                }
+            }
+        }
+
+        // Segments are drawn with a square brush moved one pixel at a time.
+        let brush = (tile / 4).max(1);
+        for line in &self.lines {
+            for segment in line.points.windows(2) {
+                let (from, to) = (center(segment[0], tile), center(segment[1], tile));
+                let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1);
+                for step in 0..=steps {
+                    let t = step as f64 / steps as f64;
+                    let x = from.0 as f64 + (to.0 as f64 - from.0 as f64) * t;
+                    let y = from.1 as f64 + (to.1 as f64 - from.1 as f64) * t;
+                    let (x, y) = (x.round() as i64, y.round() as i64);
+                    for by in 0..brush as i64 {
+                        for bx in 0..brush as i64 {
+                            let (px, py) = (x + bx - brush as i64 / 2, y + by - brush as i64 / 2);
+                            if px >= 0 && py >= 0 {
+                                put(px as usize, py as usize, line.color);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .context("could not encode the picture")?;
+        writer
+            .write_image_data(&pixels)
+            .context("could not encode the picture")?;
+        writer.finish().context("could not encode the picture")?;
+        Ok(png)
+    }
+
+    fn tiles(&self) -> impl Iterator<Item = (Pos, &Tile)> {
+        let width = self.width;
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(i, tile)| (Pos::new((i / width) as i64, (i % width) as i64), tile))
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        let (x, y) = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        (x < self.height && y < self.width).then_some(x * self.width + y)
+    }
+}
+
+/// The center of the tile at `pos`, as `(x, y)` image coordinates, when tiles are `tile` wide.
+fn center(pos: Pos, tile: usize) -> (i64, i64) {
+    let tile = tile as i64;
+    (pos.y * tile + tile / 2, pos.x * tile + tile / 2)
+}
+
+fn escape(glyph: char) -> String {
+    match glyph {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        _ => glyph.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let mut picture = Picture::new(3, 2);
+        picture.fill(Pos::new(1, 2), Rgb::RED);
+        picture.fill(Pos::new(5, 5), Rgb::RED);
+        picture.glyph(Pos::new(0, 0), '<');
+        picture.path([Pos::new(0, 0), Pos::new(0, 2)], Rgb::BLUE);
+        picture
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = picture().to_svg();
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="48" height="32""#)
+        );
+        assert!(svg.contains(r##"<rect x="32" y="16" width="16" height="16" fill="#d62728"/>"##));
+        assert_eq!(1, svg.matches("fill=\"#d62728\"").count());
+        assert!(svg.contains(r#"<text x="8" y="8">&lt;</text>"#));
+        assert!(svg.contains(r##"<polyline points="8,8 40,8" fill="none" stroke="#1f77b4""##));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_png() {
+        let png = picture().to_png().unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((48, 32), (info.width, info.height));
+
+        let at = |x: usize, y: usize| {
+            let i = (y * 48 + x) * 3;
+            Rgb(pixels[i], pixels[i + 1], pixels[i + 2])
+        };
+        assert_eq!(Rgb::RED, at(40, 24));
+        assert_eq!(Rgb::WHITE, at(8, 24));
+        assert_eq!(Rgb::BLUE, at(24, 8));
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir();
+        let err = picture().save(&dir.join("picture.txt")).unwrap_err();
+        assert!(err.to_string().contains("as .svg or .png files"));
+        assert_eq!(Some(Rgb(0x70, 0xc7, 0x10)), Rgb::from_hex("#70c710"));
+        assert_eq!(None, Rgb::from_hex("70c710"));
+        assert_eq!(Rgb(0x80, 0x80, 0x80), Rgb::BLACK.mix(Rgb::WHITE, 0.5));
+    }
+}
@@ -0,0 +1,335 @@
+//! Searches of graphs given by their start states and a function listing the
+//! neighbors of a state.
+//!
+//! [`bfs`] is for graphs whose moves all cost the same, [`dijkstra`] for
+//! weighted ones and [`astar`] for weighted ones with a heuristic guiding the
+//! search towards a goal. Each calls a hook on every state as it is visited,
+//! which can stop the search, and returns the [`Visited`] states, from which
+//! the path to any of them can be rebuilt.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Whether a search goes on after visiting a state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    Stop,
+}
+
+/// The states visited by a search, with the cost of reaching them.
+#[derive(Debug, Clone)]
+pub struct Visited<S, C> {
+    /// Where each state is in `states`.
+    index: HashMap<S, usize>,
+    /// The states in the order they were reached, with their cost and the index of the state
+    /// they were reached from, the start states having none.
+    states: Vec<(S, C, Option<usize>)>,
+    /// How many of `states` were visited, the others are waiting to be.
+    visited: usize,
+}
+
+impl<S: Eq + Hash + Clone, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            index: HashMap::new(),
+            states: Vec::new(),
+            visited: 0,
+        }
+    }
+
+    /// Records that `state` was reached, returns its index unless it was reached before.
+    fn reach(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let index = self.states.len();
+                self.states.push((entry.key().clone(), cost, parent));
+                entry.insert(index);
+                Some(index)
+            }
+        }
+    }
+
+    /// The index of the next state waiting to be visited, now marked as visited.
+    fn visit_next(&mut self) -> Option<usize> {
+        let index = self.visited;
+        (index < self.states.len()).then(|| {
+            self.visited += 1;
+            index
+        })
+    }
+
+    /// Forgets the states reached but not visited, once the search is over.
+    fn forget_unvisited(&mut self) {
+        for (state, _, _) in self.states.drain(self.visited..) {
+            self.index.remove(&state);
+        }
+    }
+
+    /// The index of `state`, if it was visited.
+    fn find(&self, state: &S) -> Option<usize> {
+        self.index
+            .get(state)
+            .copied()
+            .filter(|&index| index < self.visited)
+    }
+
+    /// The cost of reaching `state`, if it was visited.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.find(state).map(|index| self.states[index].1)
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.find(state).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.visited
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visited == 0
+    }
+
+    /// The visited states and their costs, in the order they were visited.
+    pub fn iter(&self) -> impl Iterator<Item = (&S, C)> {
+        self.states[..self.visited]
+            .iter()
+            .map(|(state, cost, _)| (state, *cost))
+    }
+
+    /// The states from a start state to `to`, both included, if `to` was visited.
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        let mut index = self.find(to);
+        let mut path = Vec::new();
+        while let Some(i) = index {
+            let (state, _, parent) = &self.states[i];
+            path.push(state.clone());
+            index = *parent;
+        }
+        path.reverse();
+        (!path.is_empty()).then_some(path)
+    }
+}
+
+/// Breadth-first search from `starts`, the cost of a state being its number of moves from the
+/// nearest start.
+///
+/// `visit` is called on each state in order of cost, with what was visited so far.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut visit: impl FnMut(&S, &Visited<S, usize>) -> Flow,
+) -> Visited<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    // The states are visited in the order they are reached, so they wait in `visited` itself.
+    let mut visited = Visited::new();
+    for start in starts {
+        visited.reach(start, 0, None);
+    }
+
+    while let Some(index) = visited.visit_next() {
+        let (state, cost, _) = visited.states[index].clone();
+        if visit(&state, &visited) == Flow::Stop {
+            break;
+        }
+        for next in neighbors(&state) {
+            visited.reach(next, cost + 1, Some(index));
+        }
+    }
+    visited.forget_unvisited();
+    visited
+}
+
+/// Cheapest paths from `starts`, `neighbors` listing the states reachable from a state along
+/// with the cost of the move, which must not be negative.
+///
+/// `visit` is called on each state in order of cost, with what was visited so far.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: impl FnMut(&S) -> I,
+    visit: impl FnMut(&S, &Visited<S, C>) -> Flow,
+) -> Visited<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), visit)
+}
+
+/// Like [`dijkstra`], but states are visited in order of their cost plus `heuristic`, an
+/// estimate of the cost left to reach the goal.
+///
+/// The heuristic must never overestimate that cost, nor decrease by more than the cost of a
+/// move, for the cost of each visited state to be the lowest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut visit: impl FnMut(&S, &Visited<S, C>) -> Flow,
+) -> Visited<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut to_visit = BinaryHeap::new();
+    for state in starts {
+        let priority = heuristic(&state);
+        to_visit.push(Waiting {
+            priority,
+            cost: C::default(),
+            state,
+            parent: None,
+        });
+    }
+
+    while let Some(Waiting {
+        cost,
+        state,
+        parent,
+        ..
+    }) = to_visit.pop()
+    {
+        let Some(index) = visited.reach(state.clone(), cost, parent) else {
+            continue;
+        };
+        visited.visit_next();
+        if visit(&state, &visited) == Flow::Stop {
+            break;
+        }
+        for (next, step) in neighbors(&state) {
+            if !visited.contains(&next) {
+                let cost = cost + step;
+                to_visit.push(Waiting {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                    parent: Some(index),
+                });
+            }
+        }
+    }
+    visited
+}
+
+/// A state waiting to be visited, the heap pops the lowest priority first.
+struct Waiting<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+    parent: Option<usize>,
+}
+
+impl<S, C: Ord> Ord for Waiting<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Waiting<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Waiting<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Waiting<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of five nodes, 0 to 4, with a shortcut from 0 to 3 costing 10.
+    fn line(node: &u32) -> Vec<(u32, u32)> {
+        let mut next = Vec::new();
+        if *node > 0 {
+            next.push((node - 1, 2));
+        }
+        if *node < 4 {
+            next.push((node + 1, 2));
+        }
+        if *node == 0 {
+            next.push((3, 10));
+        }
+        next
+    }
+
+    #[test]
+    fn test_bfs() {
+        let visited = bfs(
+            [0],
+            |node| line(node).into_iter().map(|(n, _)| n),
+            |_, _| Flow::Continue,
+        );
+        assert_eq!(5, visited.len());
+        assert_eq!(Some(1), visited.cost(&3));
+        assert_eq!(Some(2), visited.cost(&4));
+        assert_eq!(Some(vec![0, 3, 4]), visited.path(&4));
+        assert_eq!(None, visited.path(&5));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let visited = dijkstra([0], line, |_, _| Flow::Continue);
+        assert_eq!(Some(6), visited.cost(&3));
+        assert_eq!(Some(vec![0, 1, 2, 3, 4]), visited.path(&4));
+
+        let visited = dijkstra([4, 0], line, |_, _| Flow::Continue);
+        assert_eq!(Some(2), visited.cost(&3));
+        assert_eq!(Some(vec![4, 3]), visited.path(&3));
+    }
+
+    #[test]
+    fn test_astar() {
+        let mut order = Vec::new();
+        let visited = astar(
+            [0],
+            line,
+            |node| 2 * (4 - node),
+            |node, _| {
+                order.push(*node);
+                if *node == 4 {
+                    Flow::Stop
+                } else {
+                    Flow::Continue
+                }
+            },
+        );
+        assert_eq!(vec![0, 1, 2, 3, 4], order);
+        assert_eq!(Some(8), visited.cost(&4));
+        assert_eq!(Some(vec![0, 1, 2, 3, 4]), visited.path(&4));
+    }
+
+    #[test]
+    fn test_stop() {
+        let visited = bfs(
+            [0],
+            |node| [node + 1],
+            |node, visited| {
+                assert_eq!(Some(*node as usize), visited.cost(node));
+                if *node == 10 {
+                    Flow::Stop
+                } else {
+                    Flow::Continue
+                }
+            },
+        );
+        assert_eq!(11, visited.len());
+    }
+}
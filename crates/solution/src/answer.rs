@@ -0,0 +1,117 @@
+use std::{
+    fmt::Display,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign},
+    str::FromStr,
+};
+
+use num_bigint::{BigInt, ParseBigIntError};
+
+/// An integer answer of any size, for the sums and products that could outgrow the
+/// primitive types on larger inputs.
+///
+/// Anything convertible into an answer can be added to or multiplied by one, so that
+/// `items.map(...).sum::<Answer>()` accumulates without overflowing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Answer(BigInt);
+
+impl Answer {
+    pub fn pow(&self, exp: u32) -> Answer {
+        Answer(self.0.pow(exp))
+    }
+}
+
+macro_rules! from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer(BigInt::from(n))
+                }
+            }
+        )*
+    };
+}
+
+from_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        Answer(n)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Answer {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Answer)
+    }
+}
+
+impl<T: Into<Answer>> Add<T> for Answer {
+    type Output = Answer;
+
+    fn add(self, other: T) -> Answer {
+        Answer(self.0 + other.into().0)
+    }
+}
+
+impl<T: Into<Answer>> AddAssign<T> for Answer {
+    fn add_assign(&mut self, other: T) {
+        self.0 += other.into().0;
+    }
+}
+
+impl<T: Into<Answer>> Mul<T> for Answer {
+    type Output = Answer;
+
+    fn mul(self, other: T) -> Answer {
+        Answer(self.0 * other.into().0)
+    }
+}
+
+impl<T: Into<Answer>> MulAssign<T> for Answer {
+    fn mul_assign(&mut self, other: T) {
+        self.0 *= other.into().0;
+    }
+}
+
+impl<T: Into<Answer>> Sum<T> for Answer {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Answer::default(), |acc, n| acc + n)
+    }
+}
+
+impl<T: Into<Answer>> Product<T> for Answer {
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Answer::from(1), |acc, n| acc * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let big = [u64::MAX, u64::MAX, 2];
+        assert_eq!(
+            "36893488147419103232",
+            big.iter().copied().sum::<Answer>().to_string()
+        );
+        assert_eq!(
+            Ok(big.iter().copied().product::<Answer>()),
+            "680564733841876926852962238568698216450".parse()
+        );
+        assert_eq!(Answer::from(0), [0u32; 0].into_iter().sum());
+        assert_eq!(Answer::from(-6), Answer::from(2i8) * -3 + 0u8);
+        assert_eq!(Answer::from(1u128 << 100), Answer::from(2).pow(100));
+    }
+}
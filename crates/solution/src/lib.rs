@@ -0,0 +1,198 @@
+use std::{
+    cell::Cell,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use parsing::ParseError;
+
+mod answer;
+
+pub use answer::Answer;
+
+/// The shape every day follows: parse the input once, then answer both parts from it.
+///
+/// Day-specific knobs (e.g. the expansion factor of day 11) live on the implementing type,
+/// which is why the methods take `&self`.
+pub trait Solution {
+    /// The puzzle input once parsed.
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            x => Err(anyhow::anyhow!("there is no part {x}")),
+        }
+    }
+}
+
+/// How long each phase of a [`Solver::solve_timed`] call took.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    /// One per requested part, in order.
+    pub parts: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.parts.iter().sum::<Duration>()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that tooling can hold every day behind the same type.
+pub trait Solver {
+    /// Parses `input` once and answers the requested parts, in order.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+        Ok(self.solve_timed(input, parts)?.0)
+    }
+
+    /// Same as [`Solver::solve`], along with the time spent in each phase.
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<(Vec<String>, Timings)>;
+}
+
+impl<S: Solution> Solver for S {
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<(Vec<String>, Timings)> {
+        let start = Instant::now();
+        let input = self.parse(input)?;
+        let mut timings = Timings {
+            parse: start.elapsed(),
+            parts: Vec::with_capacity(parts.len()),
+        };
+
+        let mut answers = Vec::with_capacity(parts.len());
+        for part in parts {
+            let start = Instant::now();
+            answers.push(match part {
+                Part::One => self.part1(&input)?.to_string(),
+                Part::Two => self.part2(&input)?.to_string(),
+            });
+            timings.parts.push(start.elapsed());
+        }
+
+        Ok((answers, timings))
+    }
+}
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// Turns the solvers' diagnostics on, they are off by default so that only answers are printed.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Prints a diagnostic to stderr, only when [`verbose`] is on.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+thread_local! {
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, catching its panics without printing them.
+///
+/// The panic hook is replaced once, by one that leaves out the threads currently in here
+/// and hands the other panics to the previous hook, so that threads never race to swap it.
+pub fn catch_silently<R>(f: impl FnOnce() -> R) -> thread::Result<R> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let silenced = SILENCED.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.set(silenced);
+    result
+}
+
+/// Prints both answers, this is all the day binaries do.
+pub fn run(solver: &dyn Solver, input: &str) -> Result<()> {
+    let parts = [Part::One, Part::Two];
+    let answers = solver
+        .solve(input, &parts)
+        .map_err(|err| explain(err, input))?;
+    for (part, answer) in parts.iter().zip(answers) {
+        println!("Part {}: {}", part.number(), answer);
+    }
+
+    Ok(())
+}
+
+/// Points at the offending part of `input` when `err` comes from a [`ParseError`],
+/// other errors are returned as they are.
+pub fn explain(err: anyhow::Error, input: &str) -> anyhow::Error {
+    match err.chain().find_map(|e| e.downcast_ref::<ParseError>()) {
+        Some(parse_error) => {
+            anyhow::anyhow!("could not parse the input\n\n{}", parse_error.render(input))
+        }
+        None => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_silently() {
+        assert_eq!(Ok(3), catch_silently(|| 1 + 2).map_err(drop));
+        let payload = catch_silently(|| panic!("broken")).unwrap_err();
+        assert_eq!(Some(&"broken"), payload.downcast_ref::<&str>());
+        assert!(!SILENCED.with(Cell::get));
+
+        // Nested calls leave the outer one silenced.
+        let inner = catch_silently(|| {
+            let _ = catch_silently(|| panic!("inner"));
+            SILENCED.with(Cell::get)
+        });
+        assert_eq!(Ok(true), inner.map_err(drop));
+    }
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solution::Solution;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day03::Day03.parse(input);
+        let _ = input.parse::<day03::Schematic>();
+    }
+});
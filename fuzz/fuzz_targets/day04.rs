@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solution::Solution;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day04::Day04.parse(input);
+        let _ = input.parse::<day04::Card>();
+        let _ = input.parse::<day04::Input>();
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solution::Solution;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = day07::Day07.parse(input);
+        let _ = input.parse::<day07::Play>();
+        let _ = input.parse::<day07::Hand>();
+        let _ = input.parse::<day07::Card>();
+        let _ = input.parse::<day07::JokerCard>();
+        let _ = input.parse::<day07::JokerHand>();
+        let _ = input.parse::<day07::JokerPlay>();
+    }
+});